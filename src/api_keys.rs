@@ -0,0 +1,129 @@
+//! API key providers
+//!
+//! An [ElevenLabsClient](crate::ElevenLabsClient) asks its provider for a key on every
+//! request, so one client can spread requests over several keys or workspaces.
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A source of API keys for an [ElevenLabsClient](crate::ElevenLabsClient)
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let keys = Failover::new(vec!["primary_key".into(), "backup_key".into()]);
+///     let c = ElevenLabsClient::new("unused").with_api_key_provider(keys);
+///     let subscription = c.hit(GetUserSubscriptionInfo).await?;
+///     Ok(())
+/// }
+/// ```
+pub trait ApiKeyProvider: Send + Sync {
+    /// The key to use for the next request
+    fn api_key(&self) -> String;
+    /// Called when the API rejects `api_key` as unauthorized or rate limited
+    fn report_failure(&self, _api_key: &str) {}
+    /// How many keys one request may try before the error is returned
+    fn max_attempts(&self) -> usize {
+        1
+    }
+}
+
+impl ApiKeyProvider for String {
+    fn api_key(&self) -> String {
+        self.clone()
+    }
+}
+
+/// Uses each key in turn, to spread requests across keys.
+///
+/// A request rejected with one key is retried with the next.
+pub struct RoundRobin {
+    keys: Vec<String>,
+    next: AtomicUsize,
+}
+
+impl RoundRobin {
+    /// # Panics
+    ///
+    /// If `keys` is empty.
+    pub fn new(keys: Vec<String>) -> Self {
+        assert!(!keys.is_empty(), "RoundRobin needs at least one API key");
+        RoundRobin {
+            keys,
+            next: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl ApiKeyProvider for RoundRobin {
+    fn api_key(&self) -> String {
+        let i = self.next.fetch_add(1, Ordering::Relaxed);
+        self.keys[i % self.keys.len()].clone()
+    }
+    fn max_attempts(&self) -> usize {
+        self.keys.len()
+    }
+}
+
+/// Uses the first key until it is rejected, then moves on to the next one.
+///
+/// Moving past the last key wraps back to the first.
+pub struct Failover {
+    keys: Vec<String>,
+    current: AtomicUsize,
+}
+
+impl Failover {
+    /// # Panics
+    ///
+    /// If `keys` is empty.
+    pub fn new(keys: Vec<String>) -> Self {
+        assert!(!keys.is_empty(), "Failover needs at least one API key");
+        Failover {
+            keys,
+            current: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl ApiKeyProvider for Failover {
+    fn api_key(&self) -> String {
+        self.keys[self.current.load(Ordering::Relaxed)].clone()
+    }
+    fn report_failure(&self, api_key: &str) {
+        let current = self.current.load(Ordering::Relaxed);
+        // Only move on if no other request has already moved past this key
+        if self.keys[current] == api_key {
+            let next = (current + 1) % self.keys.len();
+            let _ = self.current.compare_exchange(
+                current,
+                next,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            );
+        }
+    }
+    fn max_attempts(&self) -> usize {
+        self.keys.len()
+    }
+}
+
+// Keys are secrets, so they are left out of debug output
+impl fmt::Debug for RoundRobin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RoundRobin")
+            .field("keys", &self.keys.len())
+            .finish()
+    }
+}
+
+impl fmt::Debug for Failover {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Failover")
+            .field("keys", &self.keys.len())
+            .field("current", &self.current.load(Ordering::Relaxed))
+            .finish()
+    }
+}
@@ -0,0 +1,192 @@
+//! A response cache for GET endpoints
+//!
+//! Metadata such as voices, models and the user's subscription rarely changes, so a
+//! busy service can answer most reads from memory. A [ResponseCache] set on a client
+//! caches successful JSON responses to GET requests, keyed by API key and URL, for the
+//! cache's TTL. Audio and other non-JSON responses are never cached.
+//!
+//! A successful POST or DELETE evicts the cached responses of the same resource, the
+//! first path segment after the API version. Adding a voice with `/v1/voices/add`, for
+//! example, evicts `/v1/voices` and `/v1/voices/:voice_id`, so the next
+//! [GetVoices](crate::GetVoices) is fetched again. Changes made outside the client are
+//! not seen until the TTL runs out, or until [ResponseCache::invalidate] is called.
+//!
+//! # Example
+//! ```no_run
+//! use elevenlabs_rs::*;
+//! use elevenlabs_rs::cache::ResponseCache;
+//! use std::time::Duration;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<()> {
+//!     let c = ElevenLabsClient::default()?
+//!         .with_cache(ResponseCache::new(Duration::from_secs(600)));
+//!     let voices = c.hit(GetVoices).await?;
+//!     // Answered from the cache
+//!     let voices = c.hit(GetVoices).await?;
+//!
+//!     // After a change made elsewhere, e.g. in the web app
+//!     if let Some(cache) = c.cache() {
+//!         cache.invalidate("/v1/voices");
+//!     }
+//!     Ok(())
+//! }
+//! ```
+use crate::client::Result;
+use bytes::Bytes;
+use reqwest::header::{HeaderMap, CONTENT_TYPE};
+use reqwest::{Method, Response, StatusCode, Url};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Caches GET responses in memory, see [the module docs](self)
+pub struct ResponseCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<CacheKey, CachedResponse>>,
+}
+
+/// The API key is part of the key, so workspaces sharing a client don't see each
+/// other's responses
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct CacheKey {
+    api_key: String,
+    url: String,
+}
+
+struct CachedResponse {
+    path: String,
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+    expires_at: Instant,
+}
+
+impl CachedResponse {
+    fn to_response(&self) -> Response {
+        let mut resp = http::Response::new(self.body.clone());
+        *resp.status_mut() = self.status;
+        *resp.headers_mut() = self.headers.clone();
+        Response::from(resp)
+    }
+}
+
+impl ResponseCache {
+    /// A cache keeping responses for `ttl`
+    pub fn new(ttl: Duration) -> Self {
+        ResponseCache {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+    /// The number of cached responses, expired ones included until they are next looked up
+    pub fn len(&self) -> usize {
+        self.entries().len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.entries().is_empty()
+    }
+    /// Evict the responses whose URL path starts with `path_prefix`, e.g. `/v1/voices`
+    pub fn invalidate(&self, path_prefix: &str) {
+        self.entries()
+            .retain(|_, entry| !entry.path.starts_with(path_prefix));
+    }
+    /// Evict all responses
+    pub fn clear(&self) {
+        self.entries().clear();
+    }
+
+    /// A cached response to a GET of `url`, if there is one that hasn't expired
+    pub(crate) fn get(&self, method: &Method, api_key: &str, url: &Url) -> Option<Response> {
+        if method != Method::GET {
+            return None;
+        }
+        let key = CacheKey {
+            api_key: api_key.to_string(),
+            url: url.to_string(),
+        };
+        let mut entries = self.entries();
+        match entries.get(&key) {
+            Some(entry) if entry.expires_at > Instant::now() => Some(entry.to_response()),
+            Some(_) => {
+                entries.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Cache `resp` if it is a successful JSON response to a GET, or evict the
+    /// responses of the same resource if it is a successful POST or DELETE
+    pub(crate) async fn update(
+        &self,
+        method: &Method,
+        api_key: &str,
+        url: &Url,
+        resp: Response,
+    ) -> Result<Response> {
+        if !resp.status().is_success() {
+            return Ok(resp);
+        }
+        if method != Method::GET {
+            if let Some(resource) = resource(url.path()) {
+                self.entries()
+                    .retain(|_, entry| self::resource(&entry.path) != Some(resource));
+            }
+            return Ok(resp);
+        }
+        if !is_json(resp.headers()) {
+            return Ok(resp);
+        }
+        let entry = CachedResponse {
+            path: url.path().to_string(),
+            status: resp.status(),
+            headers: resp.headers().clone(),
+            body: resp.bytes().await?,
+            expires_at: Instant::now() + self.ttl,
+        };
+        let cached = entry.to_response();
+        let key = CacheKey {
+            api_key: api_key.to_string(),
+            url: url.to_string(),
+        };
+        self.entries().insert(key, entry);
+        Ok(cached)
+    }
+
+    fn entries(&self) -> std::sync::MutexGuard<'_, HashMap<CacheKey, CachedResponse>> {
+        // The map is left consistent by every operation, so a poisoned lock is still usable
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+// Keys are secrets, so only the number of entries is shown
+impl fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("ttl", &self.ttl)
+            .field("entries", &self.len())
+            .finish()
+    }
+}
+
+/// The resource of a path, e.g. `voices` for `/v1/voices/:voice_id/settings`
+fn resource(path: &str) -> Option<&str> {
+    path.trim_start_matches('/')
+        .split('/')
+        .nth(1)
+        .filter(|segment| !segment.is_empty())
+}
+
+fn is_json(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"))
+}
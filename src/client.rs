@@ -1,51 +1,333 @@
+use crate::api_keys::ApiKeyProvider;
+#[cfg(feature = "cache")]
+use crate::cache::ResponseCache;
+use crate::endpoints::speech_to_text::realtime::{
+    InputAudioChunk, RealtimeSpeechToText, RealtimeTranscriptResponse,
+};
 use crate::endpoints::tts::ws::{EOSMessage, Flush, TextChunk, WebSocketTTS, WebSocketTTSResponse};
+use crate::endpoints::voice::{paginate_voices, Previewable, VoiceSettings, VoicesQuery};
 use crate::endpoints::{Endpoint, RequestBody};
-use crate::error::Error::HttpError;
+use crate::error::Error::{self, HttpError};
 use crate::error::{ElevenLabsClientError, ElevenLabsServerError, WebSocketError};
+use crate::shared::identifiers::{Model, VoiceID};
+use crate::shared::query_params::OutputFormat;
+use crate::utils::cost::{self, Billable, CostEstimate};
+use bytes::Bytes;
 use futures_util::{pin_mut, SinkExt, Stream, StreamExt};
 use reqwest;
-use reqwest::header::CONTENT_TYPE;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, USER_AGENT};
 use reqwest::Method;
 use reqwest::Response;
+use reqwest::{Proxy, StatusCode, Url};
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
 use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
-use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
+use tokio_tungstenite::{
+    connect_async_tls_with_config, tungstenite::protocol::Message, Connector, MaybeTlsStream,
+    WebSocketStream,
+};
 
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
 pub const BASE_URL: &str = "https://api.elevenlabs.io";
 const XI_API_KEY_HEADER: &str = "xi-api-key";
 const APPLICATION_JSON: &str = "application/json";
+const REQUEST_ID_HEADER: &str = "request-id";
+const HISTORY_ITEM_ID_HEADER: &str = "history-item-id";
+/// How long the realtime speech to text stream waits for another message after the final
+/// commit with VAD
+const VAD_FINAL_TRANSCRIPT_WAIT: Duration = Duration::from_secs(1);
+const CRATE_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 //const MULTIPART_FORM_DATA: &str = "multipart/form-data"; // Client errs with this content type
 
 #[derive(Clone)]
 pub struct ElevenLabsClient {
     inner: reqwest::Client,
-    api_key: String,
+    api_keys: Arc<dyn ApiKeyProvider>,
+    defaults: Option<DefaultSettings>,
+    base_url: Option<Url>,
+    ws: WebSocketOptions,
+    #[cfg(feature = "cache")]
+    cache: Option<Arc<ResponseCache>>,
+}
+
+/// How websocket connections are made, see [ClientBuilder::with_ws_connector]
+#[derive(Clone, Default)]
+struct WebSocketOptions {
+    connector: Option<Connector>,
+    connect_timeout: Option<Duration>,
+    /// The user agent and default headers, which reqwest adds to HTTP requests itself
+    headers: HeaderMap,
+}
+
+/// Connection pool and protocol settings for the HTTP client, see
+/// [ClientBuilder::with_pool_max_idle_per_host]
+#[derive(Clone, Debug, Default)]
+struct HttpOptions {
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    http2_adaptive_window: bool,
+    http2_keep_alive_interval: Option<Duration>,
+    http1_only: bool,
+}
+
+impl HttpOptions {
+    fn apply(self, mut builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(interval) = self.tcp_keepalive {
+            builder = builder.tcp_keepalive(interval);
+        }
+        if let Some(interval) = self.http2_keep_alive_interval {
+            builder = builder
+                .http2_keep_alive_interval(interval)
+                .http2_keep_alive_while_idle(true);
+        }
+        if self.http1_only {
+            builder = builder.http1_only();
+        } else if self.http2_adaptive_window {
+            builder = builder.http2_adaptive_window(true);
+        }
+        builder
+    }
 }
 
 impl ElevenLabsClient {
     pub fn default() -> Result<Self> {
-        Ok(Self {
-            inner: reqwest::Client::new(),
-            api_key: std::env::var("ELEVEN_API_KEY")?,
-        })
+        ClientBuilder::new().build()
     }
+    /// # Panics
+    ///
+    /// Like `reqwest::Client::new`, if the TLS backend cannot be initialized
     pub fn new<T: Into<String>>(api_key: T) -> Self {
-        Self {
-            inner: reqwest::Client::new(),
-            api_key: api_key.into(),
-        }
+        ClientBuilder::new()
+            .with_api_key(api_key)
+            .build()
+            .expect("failed to build the HTTP client")
+    }
+    /// Use `api_key` for all requests, e.g. on a clone of a shared client to act for
+    /// one tenant while reusing its connection pool
+    pub fn with_api_key<T: Into<String>>(mut self, api_key: T) -> Self {
+        self.api_keys = Arc::new(api_key.into());
+        self
+    }
+    /// Take the key for every request from `provider`, see [ApiKeyProvider]
+    pub fn with_api_key_provider<P: ApiKeyProvider + 'static>(mut self, provider: P) -> Self {
+        self.api_keys = Arc::new(provider);
+        self
+    }
+    /// A [ClientBuilder] for proxies and base URL overrides
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+    /// Settings that endpoints inherit when they are not set on the endpoint itself
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let defaults = DefaultSettings::new()
+    ///         .with_model(Model::ElevenFlashV2Dot5)
+    ///         .with_output_format(OutputFormat::Pcm16000Hz);
+    ///     let c = ElevenLabsClient::default()?.with_defaults(defaults);
+    ///     let body = TextToSpeechBody::from_text("Uses the flash model and 16kHz PCM");
+    ///     let speech = c.hit(TextToSpeech::new(PreMadeVoiceID::Sarah, body)).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_defaults(mut self, defaults: DefaultSettings) -> Self {
+        self.defaults = Some(defaults);
+        self
+    }
+    pub fn defaults(&self) -> Option<&DefaultSettings> {
+        self.defaults.as_ref()
+    }
+    /// Cache GET responses, see [cache](crate::cache). Clones of the client share the cache.
+    #[cfg(feature = "cache")]
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+    #[cfg(feature = "cache")]
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.cache.as_deref()
+    }
+    /// Estimate the characters and credits `endpoint` would use, taking the default
+    /// model into account. Nothing is sent; see [utils::cost](crate::utils::cost).
+    pub fn estimate_cost<T: Billable>(&self, endpoint: &T) -> CostEstimate {
+        let default_model = self.defaults.as_ref().and_then(|d| d.model());
+        cost::estimate(endpoint, default_model)
     }
 
     pub async fn hit<T: Endpoint>(&self, endpoint: T) -> Result<T::ResponseBody> {
+        Ok(self.hit_with_meta(endpoint).await?.into_body())
+    }
+
+    /// Send a request to any API route and return the JSON response, for routes without
+    /// an endpoint in this crate. The request goes through the same API key handling,
+    /// base URL and error handling as [hit](ElevenLabsClient::hit).
+    ///
+    /// `path` is relative to the API and starts with a single `/`. It may include a
+    /// query. Any other path, such as a full URL, returns
+    /// [Error::InvalidApiPath](crate::error::Error::InvalidApiPath), so the API key is
+    /// never sent to another host. `body` is sent as JSON with `POST`, `PATCH` and `PUT`
+    /// requests and ignored otherwise. An empty response is returned as [Value::Null].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    /// use elevenlabs_rs::endpoints::{Method, Value};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let models = c.hit_raw(Method::GET, "/v1/models", Value::Null).await?;
+    ///     println!("{:#?}", models);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn hit_raw(&self, method: Method, path: &str, body: Value) -> Result<Value> {
+        self.hit(RawEndpoint::new(method, path, body)?).await
+    }
+    /// Like [hit_raw](ElevenLabsClient::hit_raw), but returns the response body as is,
+    /// e.g. for routes that return audio
+    pub async fn hit_raw_bytes(&self, method: Method, path: &str, body: Value) -> Result<Bytes> {
+        self.hit(RawBytesEndpoint(RawEndpoint::new(method, path, body)?))
+            .await
+    }
+
+    /// The ID of the voice named `name`, searching the voices with
+    /// [GetVoicesV2](crate::GetVoicesV2). Names are compared ignoring ASCII case.
+    ///
+    /// `None` if no voice has that name.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let Some(voice_id) = c.find_voice_by_name("My Narrator").await? else {
+    ///         return Ok(());
+    ///     };
+    ///     let body = TextToSpeechBody::new("Once upon a time", Model::ElevenMultilingualV2);
+    ///     let speech = c.hit(TextToSpeech::new(voice_id, body)).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn find_voice_by_name(&self, name: &str) -> Result<Option<VoiceID>> {
+        let query = VoicesQuery::default().with_search(name).with_page_size(100);
+        let voices = paginate_voices(self, query);
+        pin_mut!(voices);
+        while let Some(voice) = voices.next().await {
+            let voice = voice?;
+            if voice.get_name().eq_ignore_ascii_case(name) {
+                return Ok(Some(VoiceID::from(voice.get_voice_id().clone())));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Download the preview clip of a voice, e.g. a [SharedVoice](crate::SharedVoice)
+    /// from the voice library, so it can be auditioned before it is added.
+    ///
+    /// The clip is fetched with this client's HTTP client, so proxy and TLS settings
+    /// apply, but without the API key, as previews are served from public storage.
+    /// Returns [Error::PreviewNotFound](crate::error::Error::PreviewNotFound) if the voice
+    /// has no preview.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let voices = c.hit(GetVoices).await?;
+    ///     for voice in voices.get_voices() {
+    ///         let preview = c.fetch_preview(voice).await?;
+    ///         std::fs::write(format!("{}.mp3", voice.get_voice_id()), &preview)?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn fetch_preview<V: Previewable + ?Sized>(&self, voice: &V) -> Result<Bytes> {
+        let url = voice.preview_url().ok_or(Error::PreviewNotFound)?;
+        let resp = self.inner.get(url).send().await?.error_for_status()?;
+        Ok(resp.bytes().await?)
+    }
+
+    /// Like [hit](ElevenLabsClient::hit), but also returns the response headers, e.g. the
+    /// request ID to pass as `previous_request_ids` or to quote in a support ticket
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let body = TextToSpeechBody::new("First paragraph.", Model::ElevenMultilingualV2);
+    ///     let resp = c.hit_with_meta(TextToSpeech::new(PreMadeVoiceID::Sarah, body)).await?;
+    ///     println!("request id: {:?}", resp.request_id());
+    ///     println!("history item id: {:?}", resp.history_item_id());
+    ///     let audio = resp.into_body();
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn hit_with_meta<T: Endpoint>(
+        &self,
+        mut endpoint: T,
+    ) -> Result<ResponseEnvelope<T::ResponseBody>> {
+        if let Some(defaults) = &self.defaults {
+            endpoint.apply_defaults(defaults);
+        }
+        let max_attempts = self.api_keys.max_attempts().max(1);
+        let mut attempt = 1;
+        loop {
+            let api_key = self.api_keys.api_key();
+            let resp = self.send(&endpoint, &api_key).await?;
+            if is_api_key_failure(resp.status()) {
+                self.api_keys.report_failure(&api_key);
+                if attempt < max_attempts {
+                    attempt += 1;
+                    continue;
+                }
+            }
+            let resp = handle_http_error(resp).await?;
+            let headers = resp.headers().clone();
+            let body = endpoint.response_body(resp).await?;
+            return Ok(ResponseEnvelope { body, headers });
+        }
+    }
+
+    async fn send<T: Endpoint>(&self, endpoint: &T, api_key: &str) -> Result<Response> {
+        let url = self.rebase(endpoint.url());
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.cache {
+            if let Some(resp) = cache.get(&endpoint.method(), api_key, &url) {
+                return Ok(resp);
+            }
+        }
         let init = self
             .inner
-            .request(endpoint.method(), endpoint.url())
-            .header(XI_API_KEY_HEADER, &self.api_key);
+            .request(endpoint.method(), url.clone())
+            .header(XI_API_KEY_HEADER, api_key);
 
         let resp = match endpoint.method() {
             Method::GET | Method::DELETE => init.send().await?,
-            Method::POST => match endpoint.request_body()? {
+            Method::POST | Method::PATCH | Method::PUT => match endpoint.request_body()? {
                 RequestBody::Json(json) => {
                     init.header(CONTENT_TYPE, APPLICATION_JSON)
                         .json(&json)
@@ -53,11 +335,17 @@ impl ElevenLabsClient {
                         .await?
                 }
                 RequestBody::Multipart(form) => init.multipart(form).send().await?,
-                RequestBody::Empty => return Err("Post request must have a body".into()),
+                RequestBody::Empty => {
+                    return Err(format!("{} request must have a body", endpoint.method()).into())
+                }
             },
             _ => return Err("Unsupported method for ElevenLabs API".into()),
         };
-        endpoint.response_body(handle_http_error(resp).await?).await
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.cache {
+            return cache.update(&endpoint.method(), api_key, &url, resp).await;
+        }
+        Ok(resp)
     }
 
     pub async fn hit_ws<S>(
@@ -67,7 +355,8 @@ impl ElevenLabsClient {
     where
         S: Stream<Item = String> + Send + 'static,
     {
-        let (ws_stream, _) = connect_async(endpoint.url()).await?;
+        let url = self.rebase(endpoint.url().parse()?);
+        let ws_stream = self.connect_ws(url.as_str()).await?;
         let (mut ws_writer, mut ws_reader) = ws_stream.split();
         let (tx, rx) = futures_channel::mpsc::unbounded::<Result<WebSocketTTSResponse>>();
 
@@ -84,11 +373,10 @@ impl ElevenLabsClient {
                             if close_frame.code == CloseCode::Normal {
                                 continue;
                             } else {
-                                tx.unbounded_send(Err(Box::new(
-                                    WebSocketError::NonNormalCloseCode(
-                                        close_frame.reason.to_string(),
-                                    ),
-                                )))?;
+                                tx.unbounded_send(Err(Box::new(WebSocketError::from_close(
+                                    close_frame.code.into(),
+                                    close_frame.reason.to_string(),
+                                ))))?;
                             }
                         } else {
                             tx.unbounded_send(Err(Box::new(
@@ -102,7 +390,7 @@ impl ElevenLabsClient {
             Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
         });
 
-        let api_key = self.api_key.clone();
+        let api_key = self.api_keys.api_key();
         tokio::spawn(async move {
             let mut bos_message = endpoint.bos_message().clone();
             bos_message = bos_message.with_api_key(&api_key);
@@ -153,6 +441,257 @@ impl ElevenLabsClient {
     }
 }
 
+impl ElevenLabsClient {
+    /// Stream audio to the realtime speech to text websocket, see [RealtimeSpeechToText]
+    pub async fn hit_stt_ws<S>(
+        &self,
+        endpoint: RealtimeSpeechToText<S>,
+    ) -> Result<impl Stream<Item = Result<RealtimeTranscriptResponse>>>
+    where
+        S: Stream<Item = Bytes> + Send + 'static,
+    {
+        let url = self.rebase(endpoint.url());
+        let mut request = url.as_str().into_client_request()?;
+        request
+            .headers_mut()
+            .insert(XI_API_KEY_HEADER, self.api_keys.api_key().parse()?);
+        let ws_stream = self.connect_ws(request).await?;
+        let (mut ws_writer, mut ws_reader) = ws_stream.split();
+        let (tx, rx) = futures_channel::mpsc::unbounded::<Result<RealtimeTranscriptResponse>>();
+        // Sent once the final commit has gone out, so transcripts committed before it, e.g.
+        // by the server's VAD, don't end the stream
+        let (commit_sent_tx, mut commit_sent) = tokio::sync::oneshot::channel::<()>();
+        let with_timestamps = endpoint.include_timestamps();
+        let commits_on_vad = endpoint.commits_on_vad();
+
+        tokio::spawn(async move {
+            let mut awaiting_commit = true;
+            let mut final_commit_sent = false;
+            // With VAD, when the stream ends if nothing else arrives
+            let mut ends_at = None;
+            loop {
+                let msg_result = tokio::select! {
+                    // Checked first, so a transcript read after the commit went out counts
+                    biased;
+                    sent = &mut commit_sent, if awaiting_commit => {
+                        awaiting_commit = false;
+                        final_commit_sent = sent.is_ok();
+                        // The server's VAD may already have committed all the audio, in
+                        // which case the final commit gets no transcript at all
+                        if final_commit_sent && commits_on_vad {
+                            ends_at = Some(tokio::time::Instant::now() + VAD_FINAL_TRANSCRIPT_WAIT);
+                        }
+                        continue;
+                    }
+                    _ = tokio::time::sleep_until(ends_at.unwrap_or_else(tokio::time::Instant::now)),
+                        if ends_at.is_some() => break,
+                    msg_result = ws_reader.next() => match msg_result {
+                        Some(msg_result) => msg_result,
+                        None => break,
+                    },
+                };
+                let msg = msg_result?;
+                match msg {
+                    Message::Text(text) => {
+                        let response = RealtimeTranscriptResponse::from_frame(&text);
+                        let committed =
+                            matches!(&response, Ok(r) if r.is_committed(with_timestamps));
+                        tx.unbounded_send(response)?;
+                        if final_commit_sent {
+                            // Without VAD, only the final commit's transcript can follow it.
+                            // With VAD, one the server committed itself may come first.
+                            if committed && !commits_on_vad {
+                                break;
+                            }
+                            if commits_on_vad {
+                                ends_at =
+                                    Some(tokio::time::Instant::now() + VAD_FINAL_TRANSCRIPT_WAIT);
+                            }
+                        }
+                    }
+                    Message::Close(msg) => {
+                        if let Some(close_frame) = msg {
+                            if close_frame.code != CloseCode::Normal {
+                                tx.unbounded_send(Err(Box::new(WebSocketError::from_close(
+                                    close_frame.code.into(),
+                                    close_frame.reason.to_string(),
+                                ))))?;
+                            }
+                        } else {
+                            tx.unbounded_send(Err(Box::new(
+                                WebSocketError::ClosedWithoutCloseFrame,
+                            )))?;
+                        }
+                        break;
+                    }
+                    Message::Ping(_) | Message::Pong(_) => {}
+                    _ => tx.unbounded_send(Err(Box::new(WebSocketError::UnexpectedMessageType)))?,
+                }
+            }
+            Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+        });
+
+        tokio::spawn(async move {
+            let sample_rate = endpoint.audio_encoding().sample_rate();
+            let audio_stream = endpoint.audio_stream();
+            pin_mut!(audio_stream);
+            while let Some(chunk) = audio_stream.next().await {
+                let chunk = InputAudioChunk::new(&chunk, false, sample_rate);
+                ws_writer.send(Message::text(chunk.json()?)).await?;
+            }
+            let commit = InputAudioChunk::new(&[], true, sample_rate);
+            ws_writer.send(Message::text(commit.json()?)).await?;
+            // The reader may already be gone
+            let _ = commit_sent_tx.send(());
+            Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+        });
+        Ok(rx)
+    }
+
+    async fn connect_ws<R>(&self, request: R) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>>
+    where
+        R: IntoClientRequest + Unpin,
+    {
+        let mut request = request.into_client_request()?;
+        for (name, value) in &self.ws.headers {
+            if !request.headers().contains_key(name) {
+                request.headers_mut().insert(name, value.clone());
+            }
+        }
+        let connector = self.ws.connector.clone();
+        let connect = connect_async_tls_with_config(request, None, false, connector);
+        let (ws_stream, _) = match self.ws.connect_timeout {
+            Some(timeout) => tokio::time::timeout(timeout, connect)
+                .await
+                .map_err(|_| WebSocketError::ConnectTimeout)??,
+            None => connect.await?,
+        };
+        Ok(ws_stream)
+    }
+
+    /// Move an endpoint URL built against [BASE_URL] onto the configured base URL,
+    /// keeping any path prefix of the base
+    fn rebase(&self, mut url: Url) -> Url {
+        let Some(base) = &self.base_url else {
+            return url;
+        };
+        let scheme = match (url.scheme(), base.scheme()) {
+            ("wss", "http") => "ws",
+            ("wss", _) => "wss",
+            (_, scheme) => scheme,
+        };
+        let path = format!("{}{}", base.path().trim_end_matches('/'), url.path());
+        // The endpoint URL is always an absolute https/wss URL, so these cannot fail
+        let _ = url.set_scheme(scheme);
+        let _ = url.set_host(base.host_str());
+        let _ = url.set_port(base.port());
+        url.set_path(&path);
+        url
+    }
+}
+
+/// The endpoint behind [ElevenLabsClient::hit_raw]
+struct RawEndpoint {
+    method: Method,
+    url: Url,
+    body: Value,
+}
+
+impl RawEndpoint {
+    /// `path` must be relative to the API, e.g. `/v1/models`, so the API key is never
+    /// sent to another host
+    fn new(method: Method, path: &str, body: Value) -> Result<Self> {
+        if !path.starts_with('/') || path.starts_with("//") || path.starts_with("/\\") {
+            return Err(Box::new(Error::InvalidApiPath(path.to_string())));
+        }
+        let (path, query) = match path.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (path, None),
+        };
+        let mut url = BASE_URL.parse::<Url>()?;
+        url.set_path(path);
+        url.set_query(query);
+        Ok(RawEndpoint { method, url, body })
+    }
+}
+
+impl Endpoint for RawEndpoint {
+    type ResponseBody = Value;
+
+    fn method(&self) -> Method {
+        self.method.clone()
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(self.body.clone()))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        let bytes = resp.bytes().await?;
+        if bytes.is_empty() {
+            return Ok(Value::Null);
+        }
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+    fn url(&self) -> Url {
+        self.url.clone()
+    }
+}
+
+/// The endpoint behind [ElevenLabsClient::hit_raw_bytes]
+struct RawBytesEndpoint(RawEndpoint);
+
+impl Endpoint for RawBytesEndpoint {
+    type ResponseBody = Bytes;
+
+    fn method(&self) -> Method {
+        self.0.method()
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        self.0.request_body()
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.bytes().await?)
+    }
+    fn url(&self) -> Url {
+        self.0.url()
+    }
+}
+
+/// A response body with the headers it came with, see [ElevenLabsClient::hit_with_meta]
+#[derive(Clone, Debug)]
+pub struct ResponseEnvelope<T> {
+    body: T,
+    headers: HeaderMap,
+}
+
+impl<T> ResponseEnvelope<T> {
+    pub fn body(&self) -> &T {
+        &self.body
+    }
+    pub fn into_body(self) -> T {
+        self.body
+    }
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+    /// A header value, if present and valid UTF-8
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+    /// The `request-id` header
+    pub fn request_id(&self) -> Option<&str> {
+        self.header(REQUEST_ID_HEADER)
+    }
+    /// The `history-item-id` header, sent by endpoints that create a history item
+    pub fn history_item_id(&self) -> Option<&str> {
+        self.header(HISTORY_ITEM_ID_HEADER)
+    }
+}
+
+/// Unauthorized or rate limited, where another key may succeed
+fn is_api_key_failure(status: StatusCode) -> bool {
+    status == StatusCode::UNAUTHORIZED || status == StatusCode::TOO_MANY_REQUESTS
+}
+
 async fn handle_http_error(resp: Response) -> Result<Response> {
     if resp.status().is_server_error() {
         let server_error = resp.json::<ElevenLabsServerError>().await?;
@@ -173,7 +712,298 @@ impl From<(reqwest::Client, String)> for ElevenLabsClient {
     fn from((client, api_key): (reqwest::Client, String)) -> Self {
         Self {
             inner: client,
-            api_key,
+            api_keys: Arc::new(api_key),
+            defaults: None,
+            base_url: None,
+            ws: WebSocketOptions::default(),
+            #[cfg(feature = "cache")]
+            cache: None,
+        }
+    }
+}
+
+/// Builder for an [ElevenLabsClient] with a proxy, a custom base URL or connection
+/// settings
+///
+/// HTTP/2 is negotiated with the API over TLS, so concurrent requests share one
+/// connection per host. For high throughput batch jobs, keep more idle connections
+/// with [with_pool_max_idle_per_host](ClientBuilder::with_pool_max_idle_per_host) and
+/// let HTTP/2 size its flow control window to the link with
+/// [with_http2_adaptive_window](ClientBuilder::with_http2_adaptive_window).
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::builder()
+///         .with_base_url("https://api.eu.residency.elevenlabs.io")?
+///         .with_proxy("http://proxy.internal:3128")?
+///         .build()?;
+///     let speech = c
+///         .hit(TextToSpeech::new(
+///             PreMadeVoiceID::Sarah,
+///             TextToSpeechBody::new("Hello from the EU", Model::ElevenFlashV2Dot5),
+///         ))
+///         .await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Default)]
+pub struct ClientBuilder {
+    api_keys: Option<Arc<dyn ApiKeyProvider>>,
+    base_url: Option<Url>,
+    proxies: Vec<Proxy>,
+    defaults: Option<DefaultSettings>,
+    root_certificates: Vec<Vec<u8>>,
+    http: HttpOptions,
+    applications: Vec<String>,
+    headers: HeaderMap,
+    ws: WebSocketOptions,
+    #[cfg(feature = "cache")]
+    cache: Option<ResponseCache>,
+}
+
+impl ClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The API key, the `ELEVEN_API_KEY` environment variable is used if not set
+    pub fn with_api_key<T: Into<String>>(mut self, api_key: T) -> Self {
+        self.api_keys = Some(Arc::new(api_key.into()));
+        self
+    }
+    pub fn with_api_key_provider<P: ApiKeyProvider + 'static>(mut self, provider: P) -> Self {
+        self.api_keys = Some(Arc::new(provider));
+        self
+    }
+    /// Send requests to `base_url` instead of [BASE_URL], e.g. a data residency endpoint
+    /// or an API gateway. A path in `base_url` is kept as a prefix of every endpoint path.
+    ///
+    /// Websocket endpoints use the same host, with `wss` for an `https` base URL.
+    pub fn with_base_url(mut self, base_url: &str) -> Result<Self> {
+        self.base_url = Some(base_url.parse()?);
+        Ok(self)
+    }
+    /// Route all requests through a proxy, e.g. `http://proxy:3128`.
+    ///
+    /// SOCKS proxies also need reqwest's `socks` feature enabled in your own manifest.
+    pub fn with_proxy(mut self, proxy_url: &str) -> Result<Self> {
+        self.proxies.push(Proxy::all(proxy_url)?);
+        Ok(self)
+    }
+    /// Add a proxy with reqwest's own configuration, e.g. one scoped to https or with
+    /// basic auth
+    pub fn with_reqwest_proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+    pub fn with_defaults(mut self, defaults: DefaultSettings) -> Self {
+        self.defaults = Some(defaults);
+        self
+    }
+    /// Trust the PEM encoded root certificate `pem` for HTTP and websocket connections,
+    /// e.g. for a TLS-inspecting proxy with a private CA
+    pub fn with_root_certificate_pem(mut self, pem: &[u8]) -> Result<Self> {
+        // Checked here so an invalid certificate fails before build
+        native_tls::Certificate::from_pem(pem)?;
+        self.root_certificates.push(pem.to_vec());
+        Ok(self)
+    }
+    /// How long connecting may take, for HTTP and websocket connections
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.ws.connect_timeout = Some(timeout);
+        self
+    }
+    /// Append `name/version` to the User-Agent, e.g. for partner attribution.
+    ///
+    /// Clients built with the builder send `elevenlabs_rs/<crate version>`, followed by
+    /// each application in the order they were added.
+    pub fn with_application(mut self, name: &str, version: &str) -> Self {
+        self.applications.push(format!("{}/{}", name, version));
+        self
+    }
+    /// Send `name: value` with every HTTP and websocket request, e.g. for routing by an
+    /// API gateway. Headers set by an endpoint, such as the API key, take precedence.
+    pub fn with_default_header(mut self, name: &str, value: &str) -> Result<Self> {
+        self.headers
+            .insert(HeaderName::try_from(name)?, HeaderValue::try_from(value)?);
+        Ok(self)
+    }
+    /// Like [with_default_header](ClientBuilder::with_default_header), for several headers
+    pub fn with_default_headers(mut self, headers: HeaderMap) -> Self {
+        self.headers.extend(headers);
+        self
+    }
+    /// The most idle connections kept open per host, unlimited by default
+    pub fn with_pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.http.pool_max_idle_per_host = Some(max);
+        self
+    }
+    /// How long an idle connection is kept open, 90 seconds by default
+    pub fn with_pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.http.pool_idle_timeout = Some(timeout);
+        self
+    }
+    /// Send TCP keepalive probes at `interval`, so idle connections survive NAT and
+    /// load balancer timeouts
+    pub fn with_tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.http.tcp_keepalive = Some(interval);
+        self
+    }
+    /// Let HTTP/2 connections adjust their flow control window to the bandwidth and
+    /// latency of the link, which speeds up large audio downloads
+    pub fn with_http2_adaptive_window(mut self, enabled: bool) -> Self {
+        self.http.http2_adaptive_window = enabled;
+        self
+    }
+    /// Ping HTTP/2 connections at `interval`, including idle ones, to detect dead
+    /// connections before a request is sent on them
+    pub fn with_http2_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.http.http2_keep_alive_interval = Some(interval);
+        self
+    }
+    /// Only use HTTP/1.1, e.g. behind a proxy that mishandles HTTP/2
+    pub fn with_http1_only(mut self) -> Self {
+        self.http.http1_only = true;
+        self
+    }
+    /// The TLS connector for websocket connections, replacing the default native-tls one.
+    ///
+    /// A rustls connector needs one of tokio-tungstenite's rustls features enabled in your
+    /// own manifest. Root certificates from
+    /// [with_root_certificate_pem](ClientBuilder::with_root_certificate_pem) only apply to
+    /// the HTTP client when a connector is set.
+    pub fn with_ws_connector(mut self, connector: Connector) -> Self {
+        self.ws.connector = Some(connector);
+        self
+    }
+    #[cfg(feature = "cache")]
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+    pub fn build(self) -> Result<ElevenLabsClient> {
+        let api_keys = match self.api_keys {
+            Some(api_keys) => api_keys,
+            None => Arc::new(std::env::var("ELEVEN_API_KEY")?),
+        };
+        let mut user_agent = CRATE_USER_AGENT.to_string();
+        for application in &self.applications {
+            user_agent.push(' ');
+            user_agent.push_str(application);
+        }
+        let mut headers = self.headers;
+        headers.insert(USER_AGENT, HeaderValue::try_from(user_agent)?);
+        let mut builder = self
+            .http
+            .apply(reqwest::Client::builder())
+            .default_headers(headers.clone());
+        for proxy in self.proxies {
+            builder = builder.proxy(proxy);
+        }
+        if let Some(timeout) = self.ws.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        let mut ws = self.ws;
+        ws.headers = headers;
+        if !self.root_certificates.is_empty() && ws.connector.is_none() {
+            let mut tls = native_tls::TlsConnector::builder();
+            for pem in &self.root_certificates {
+                builder = builder.add_root_certificate(reqwest::Certificate::from_pem(pem)?);
+                tls.add_root_certificate(native_tls::Certificate::from_pem(pem)?);
+            }
+            ws.connector = Some(Connector::NativeTls(tls.build()?));
+        } else {
+            for pem in &self.root_certificates {
+                builder = builder.add_root_certificate(reqwest::Certificate::from_pem(pem)?);
+            }
+        }
+        Ok(ElevenLabsClient {
+            inner: builder.build()?,
+            api_keys,
+            defaults: self.defaults,
+            base_url: self.base_url,
+            ws,
+            #[cfg(feature = "cache")]
+            cache: self.cache.map(Arc::new),
+        })
+    }
+}
+
+/// Client-wide defaults, see [ElevenLabsClient::with_defaults]
+///
+/// The model applies to text to speech bodies created without one, voice settings to
+/// text to speech and speech to speech bodies, and the output format to any endpoint
+/// with an output format query.
+#[derive(Clone, Debug, Default)]
+pub struct DefaultSettings {
+    model: Option<Model>,
+    voice_settings: Option<VoiceSettings>,
+    output_format: Option<OutputFormat>,
+}
+
+impl DefaultSettings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_model(mut self, model: Model) -> Self {
+        self.model = Some(model);
+        self
+    }
+    pub fn with_voice_settings(mut self, voice_settings: VoiceSettings) -> Self {
+        self.voice_settings = Some(voice_settings);
+        self
+    }
+    pub fn with_output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = Some(output_format);
+        self
+    }
+    pub fn model(&self) -> Option<&Model> {
+        self.model.as_ref()
+    }
+    pub fn voice_settings(&self) -> Option<&VoiceSettings> {
+        self.voice_settings.as_ref()
+    }
+    pub fn output_format(&self) -> Option<&OutputFormat> {
+        self.output_format.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_endpoint_keeps_api_host() {
+        let endpoint =
+            RawEndpoint::new(Method::GET, "/v1/voices?page_size=10", Value::Null).unwrap();
+        assert_eq!(
+            endpoint.url().as_str(),
+            "https://api.elevenlabs.io/v1/voices?page_size=10"
+        );
+    }
+
+    #[test]
+    fn new_sets_user_agent() {
+        let client = ElevenLabsClient::new("key");
+        assert_eq!(client.ws.headers[USER_AGENT], CRATE_USER_AGENT);
+    }
+
+    #[test]
+    fn raw_endpoint_rejects_other_hosts() {
+        for path in [
+            "https://other.host/x",
+            "//other.host/x",
+            "/\\other.host/x",
+            "v1/voices",
+            "",
+        ] {
+            assert!(
+                RawEndpoint::new(Method::GET, path, Value::Null).is_err(),
+                "{path}"
+            );
         }
     }
 }
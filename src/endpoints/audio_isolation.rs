@@ -38,11 +38,19 @@ const AUDIO_ISOLATION_PATH: &str = "v1/audio-isolation";
 #[derive(Clone, Debug)]
 pub struct AudioIsolation {
     pub audio_file: String,
+    upload: Option<FileUpload>,
 }
 
 impl AudioIsolation {
     pub fn new<T: Into<String> >(audio_file: T) -> Self {
-        Self { audio_file: audio_file.into() }
+        Self { audio_file: audio_file.into(), upload: None }
+    }
+    /// Isolate audio that is already in memory
+    pub fn from_upload(upload: FileUpload) -> Self {
+        Self {
+            audio_file: upload.file_name().to_string(),
+            upload: Some(upload),
+        }
     }
 }
 
@@ -54,7 +62,7 @@ impl Endpoint for AudioIsolation {
         Method::POST
     }
     fn request_body(&self) -> Result<RequestBody> {
-        Ok(RequestBody::Multipart(to_form(&self.audio_file)?))
+        Ok(RequestBody::Multipart(to_form(&self.audio_file, self.upload.as_ref())?))
     }
     async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
         //Ok(resp.json().await?)
@@ -88,11 +96,19 @@ impl Endpoint for AudioIsolation {
 #[derive(Clone, Debug)]
 pub struct AudioIsolationStream {
     pub audio_file: String,
+    upload: Option<FileUpload>,
 }
 
 impl AudioIsolationStream {
     pub fn new<T: Into<String> >(audio_file: T) -> Self {
-        Self { audio_file: audio_file.into() }
+        Self { audio_file: audio_file.into(), upload: None }
+    }
+    /// Isolate audio that is already in memory
+    pub fn from_upload(upload: FileUpload) -> Self {
+        Self {
+            audio_file: upload.file_name().to_string(),
+            upload: Some(upload),
+        }
     }
 }
 
@@ -104,7 +120,7 @@ impl Endpoint for AudioIsolationStream {
         Method::POST
     }
     fn request_body(&self) -> Result<RequestBody> {
-        Ok(RequestBody::Multipart(to_form(&self.audio_file)?))
+        Ok(RequestBody::Multipart(to_form(&self.audio_file, self.upload.as_ref())?))
     }
     async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
         let stream = resp.bytes_stream();
@@ -119,8 +135,11 @@ impl Endpoint for AudioIsolationStream {
 }
 
 
-fn to_form(audio_file: &str) -> Result<Form> {
+fn to_form(audio_file: &str, upload: Option<&FileUpload>) -> Result<Form> {
     let mut form = Form::new();
+    if let Some(upload) = upload {
+        return Ok(form.part("audio", upload.to_part()?));
+    }
     let path = Path::new(audio_file);
     let audio_bytes = std::fs::read(audio_file)?;
     let mut part = Part::bytes(audio_bytes);
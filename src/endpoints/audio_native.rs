@@ -1,10 +1,38 @@
 #![allow(dead_code)]
-//! The audio native endpoint
+//! The audio native endpoints
 use super::*;
+use crate::error::Error;
+use std::path::Path;
 
 const AUDIO_NATIVE_PATH: &str = "/v1/audio-native";
+const SETTINGS_PATH: &str = "/settings";
+const CONTENT_PATH: &str = "/content";
 
 /// Audio Native endpoint
+///
+/// Creates an Audio Native enabled project, optionally converting an HTML or text file,
+/// and returns the embeddable player's HTML snippet.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = AudioNativeBody::new("My blog post")
+///         .with_title("Why Rust")
+///         .with_author("Ferris")
+///         .with_text_color("#000000")
+///         .with_background_color("#FFFFFF")
+///         .with_sessionization(5)
+///         .with_file("post.html")
+///         .with_auto_convert();
+///     let resp = c.hit(AudioNative::new(body)).await?;
+///     println!("{}", resp.html_snippet());
+///     Ok(())
+/// }
+/// ```
 #[derive(Debug, Clone)]
 pub struct AudioNative(AudioNativeBody);
 
@@ -26,10 +54,37 @@ pub struct AudioNativeBody {
     sessionization: Option<u32>,
     voice_id: Option<String>,
     model_id: Option<String>,
-    file: Option<String>,
+    file: Option<ContentFile>,
     auto_convert: Option<bool>,
 }
 
+/// An HTML or text file to convert, either on disk or already in memory
+#[derive(Clone, Debug)]
+enum ContentFile {
+    Path(String),
+    Upload(FileUpload),
+}
+
+impl ContentFile {
+    fn to_part(&self) -> Result<Part> {
+        match self {
+            ContentFile::Path(path) => {
+                let path = Path::new(path);
+                let mime_type = match path.extension().and_then(|ext| ext.to_str()) {
+                    Some("html") | Some("htm") => "text/html",
+                    _ => "text/plain",
+                };
+                let file_name = path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .ok_or(Box::new(Error::PathNotValidUTF8))?;
+                FileUpload::new(std::fs::read(path)?, file_name, mime_type).to_part()
+            }
+            ContentFile::Upload(upload) => upload.to_part(),
+        }
+    }
+}
+
 impl AudioNativeBody {
     pub fn new(name: &str) -> Self {
         AudioNativeBody {
@@ -73,8 +128,14 @@ impl AudioNativeBody {
         self.model_id = Some(model_id.to_string());
         self
     }
+    /// Path to an HTML or text file whose content is converted into audio
     pub fn with_file(mut self, file: &str) -> Self {
-        self.file = Some(file.to_string());
+        self.file = Some(ContentFile::Path(file.to_string()));
+        self
+    }
+    /// An in-memory HTML or text file whose content is converted into audio
+    pub fn with_file_upload(mut self, file: FileUpload) -> Self {
+        self.file = Some(ContentFile::Upload(file));
         self
     }
     pub fn with_auto_convert(mut self) -> Self {
@@ -112,7 +173,7 @@ impl AudioNativeBody {
             form = form.text("model_id", model_id);
         }
         if let Some(file) = self.file {
-            form = form.text("file", file);
+            form = form.part("file", file.to_part()?);
         }
         if let Some(auto_convert) = self.auto_convert {
             form = form.text("auto_convert", auto_convert.to_string());
@@ -142,9 +203,261 @@ impl Endpoint for AudioNative {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct AudioNativeResponseBody {
     project_id: String,
     converting: bool,
     html_snippet: String,
 }
+
+impl AudioNativeResponseBody {
+    pub fn project_id(&self) -> &str {
+        &self.project_id
+    }
+    pub fn converting(&self) -> bool {
+        self.converting
+    }
+    pub fn html_snippet(&self) -> &str {
+        &self.html_snippet
+    }
+}
+
+/// Get the player settings of an Audio Native project
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let resp = c.hit(GetAudioNativeSettings::new("some_project_id")).await?;
+///     println!("{:#?}", resp);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetAudioNativeSettings {
+    project_id: String,
+}
+
+impl GetAudioNativeSettings {
+    pub fn new(project_id: &str) -> Self {
+        GetAudioNativeSettings {
+            project_id: project_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for GetAudioNativeSettings {
+    type ResponseBody = AudioNativeSettingsResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(&format!(
+            "{}/{}{}",
+            AUDIO_NATIVE_PATH, self.project_id, SETTINGS_PATH
+        ));
+        url
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AudioNativeSettingsResponse {
+    enabled: bool,
+    snapshot_id: Option<String>,
+    settings: Option<AudioNativeSettings>,
+}
+
+impl AudioNativeSettingsResponse {
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+    pub fn snapshot_id(&self) -> Option<&str> {
+        self.snapshot_id.as_deref()
+    }
+    pub fn settings(&self) -> Option<&AudioNativeSettings> {
+        self.settings.as_ref()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AudioNativeSettings {
+    title: Option<String>,
+    image: Option<String>,
+    author: Option<String>,
+    small: Option<bool>,
+    text_color: Option<String>,
+    background_color: Option<String>,
+    sessionization: Option<u32>,
+    audio_path: Option<String>,
+    audio_url: Option<String>,
+    status: Option<String>,
+}
+
+impl AudioNativeSettings {
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+    pub fn image(&self) -> Option<&str> {
+        self.image.as_deref()
+    }
+    pub fn author(&self) -> Option<&str> {
+        self.author.as_deref()
+    }
+    pub fn small(&self) -> Option<bool> {
+        self.small
+    }
+    pub fn text_color(&self) -> Option<&str> {
+        self.text_color.as_deref()
+    }
+    pub fn background_color(&self) -> Option<&str> {
+        self.background_color.as_deref()
+    }
+    pub fn sessionization(&self) -> Option<u32> {
+        self.sessionization
+    }
+    pub fn audio_path(&self) -> Option<&str> {
+        self.audio_path.as_deref()
+    }
+    pub fn audio_url(&self) -> Option<&str> {
+        self.audio_url.as_deref()
+    }
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+}
+
+/// Replace the content of an Audio Native project with a new HTML or text file
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = UpdateAudioNativeContentBody::new()
+///         .with_file("post_v2.html")
+///         .with_auto_convert()
+///         .with_auto_publish();
+///     let resp = c
+///         .hit(UpdateAudioNativeContent::new("some_project_id", body))
+///         .await?;
+///     println!("{:#?}", resp);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct UpdateAudioNativeContent {
+    project_id: String,
+    body: UpdateAudioNativeContentBody,
+}
+
+impl UpdateAudioNativeContent {
+    pub fn new(project_id: &str, body: UpdateAudioNativeContentBody) -> Self {
+        UpdateAudioNativeContent {
+            project_id: project_id.to_string(),
+            body,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct UpdateAudioNativeContentBody {
+    file: Option<ContentFile>,
+    auto_convert: Option<bool>,
+    auto_publish: Option<bool>,
+}
+
+impl UpdateAudioNativeContentBody {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Path to an HTML or text file to replace the content with
+    pub fn with_file(mut self, file: &str) -> Self {
+        self.file = Some(ContentFile::Path(file.to_string()));
+        self
+    }
+    /// An in-memory HTML or text file to replace the content with
+    pub fn with_file_upload(mut self, file: FileUpload) -> Self {
+        self.file = Some(ContentFile::Upload(file));
+        self
+    }
+    pub fn with_auto_convert(mut self) -> Self {
+        self.auto_convert = Some(true);
+        self
+    }
+    pub fn with_auto_publish(mut self) -> Self {
+        self.auto_publish = Some(true);
+        self
+    }
+    fn to_form(&self) -> Result<Form> {
+        let mut form = Form::new();
+        if let Some(file) = &self.file {
+            form = form.part("file", file.to_part()?);
+        }
+        if let Some(auto_convert) = self.auto_convert {
+            form = form.text("auto_convert", auto_convert.to_string());
+        }
+        if let Some(auto_publish) = self.auto_publish {
+            form = form.text("auto_publish", auto_publish.to_string());
+        }
+        Ok(form)
+    }
+}
+
+impl Endpoint for UpdateAudioNativeContent {
+    type ResponseBody = UpdateAudioNativeContentResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Multipart(self.body.to_form()?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(&format!(
+            "{}/{}{}",
+            AUDIO_NATIVE_PATH, self.project_id, CONTENT_PATH
+        ));
+        url
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct UpdateAudioNativeContentResponse {
+    project_id: String,
+    converting: bool,
+    publishing: bool,
+    html_snippet: String,
+}
+
+impl UpdateAudioNativeContentResponse {
+    pub fn project_id(&self) -> &str {
+        &self.project_id
+    }
+    pub fn converting(&self) -> bool {
+        self.converting
+    }
+    pub fn publishing(&self) -> bool {
+        self.publishing
+    }
+    pub fn html_snippet(&self) -> &str {
+        &self.html_snippet
+    }
+}
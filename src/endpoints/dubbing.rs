@@ -1,7 +1,7 @@
 #![allow(dead_code)]
 //! The dubbing endpoints
 use super::*;
-use crate::error::Error;
+use crate::error::{DubbingError, Error};
 use std::path::Path;
 
 const DUBBING_PATH: &str = "v1/dubbing";
@@ -54,6 +54,36 @@ impl DubAVideoOrAnAudioFile {
         DubAVideoOrAnAudioFile::new(body)
     }
 
+    /// Create a dub from a video or audio file that is already in memory.
+    ///
+    /// # Example
+    ///```no_run
+    /// use elevenlabs_rs::*;
+    /// use elevenlabs_rs::endpoints::dubbing::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///    let c = ElevenLabsClient::default()?;
+    ///    let bytes = Bytes::from(std::fs::read("some_video_file.mp4")?);
+    ///    let file = FileUpload::new(bytes, "some_video_file.mp4", "video/mp4");
+    ///    let endpoint = DubAVideoOrAnAudioFile::from_upload(file, "ja", "en");
+    ///    let resp = c.hit(endpoint).await?;
+    ///    println!("{:#?}", resp);
+    ///    Ok(())
+    /// }
+    ///```
+    pub fn from_upload(file: FileUpload, source_language: &str, target_language: &str) -> Self {
+        let mut body = DubbingBody::default();
+        body = body
+            .with_file_upload(file)
+            .with_source_lang(source_language)
+            .with_target_lang(target_language)
+            .with_mode(Mode::Automatic)
+            .with_num_speakers(1)
+            .with_watermark(false);
+        DubAVideoOrAnAudioFile::new(body)
+    }
+
     /// Create a dub from a link to a video or audio file.
     ///
     /// # Example
@@ -91,6 +121,7 @@ impl Endpoint for DubAVideoOrAnAudioFile {
         Method::POST
     }
     fn request_body(&self) -> Result<RequestBody> {
+        self.0.validate()?;
         Ok(RequestBody::Multipart(to_form(self.0.clone())?))
     }
     async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
@@ -104,7 +135,8 @@ impl Endpoint for DubAVideoOrAnAudioFile {
 }
 
 /// Response body for dubbing a video or audio file.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct DubAVideoOrAnAudioFileResponse {
     dubbing_id: String,
     expected_duration_sec: f32,
@@ -145,7 +177,12 @@ impl DubAVideoOrAnAudioFileResponse {
 ///    // Whether to use the highest resolution available for the dubbing. Default is false.
 ///    .with_highest_resolution(true)
 ///    // Whether to prepare the dub for edits in the dubbing studio. Default is false.
-///    .with_dubbing_studio(false);
+///    .with_dubbing_studio(false)
+///    // Whether to leave the background audio out of the dub. Default is false.
+///    .with_drop_background_audio(false)
+///    // Whether to censor profanities in the transcript. Default is false.
+///    .with_use_profanity_filter(false);
+/// assert!(body.validate().is_ok());
 /// ```
 /// See [ElevenLabs API documentation](https://elevenlabs.io/docs/api-reference/create-dub) for more information.
 ///
@@ -154,9 +191,12 @@ impl DubAVideoOrAnAudioFileResponse {
 pub struct DubbingBody {
     mode: Option<Mode>,
     file: Option<String>,
+    file_upload: Option<FileUpload>,
     csv_file: Option<String>,
     foreground_audio_file: Option<String>,
+    foreground_audio_upload: Option<FileUpload>,
     background_audio_file: Option<String>,
+    background_audio_upload: Option<FileUpload>,
     name: Option<String>,
     source_url: Option<String>,
     source_lang: Option<String>,
@@ -167,6 +207,9 @@ pub struct DubbingBody {
     end_time: Option<f32>,
     highest_resolution: Option<bool>,
     dubbing_studio: Option<bool>,
+    drop_background_audio: Option<bool>,
+    use_profanity_filter: Option<bool>,
+    disable_voice_cloning: Option<bool>,
 }
 
 #[derive(Clone, Debug)]
@@ -189,9 +232,12 @@ impl DubbingBody {
         DubbingBody {
             mode: None,
             file: None,
+            file_upload: None,
             csv_file: None,
             foreground_audio_file: None,
+            foreground_audio_upload: None,
             background_audio_file: None,
+            background_audio_upload: None,
             name: None,
             source_url: None,
             source_lang: None,
@@ -202,6 +248,9 @@ impl DubbingBody {
             end_time: None,
             highest_resolution: None,
             dubbing_studio: None,
+            drop_background_audio: None,
+            use_profanity_filter: None,
+            disable_voice_cloning: None,
         }
     }
 
@@ -214,6 +263,12 @@ impl DubbingBody {
         self
     }
 
+    /// Dub a video or audio file that is already in memory
+    pub fn with_file_upload(mut self, file: FileUpload) -> Self {
+        self.file_upload = Some(file);
+        self
+    }
+
     pub fn with_csv_file(mut self, csv_file: String) -> Self {
         self.csv_file = Some(csv_file);
         self
@@ -229,6 +284,16 @@ impl DubbingBody {
         self
     }
 
+    pub fn with_foreground_audio_upload(mut self, foreground_audio: FileUpload) -> Self {
+        self.foreground_audio_upload = Some(foreground_audio);
+        self
+    }
+
+    pub fn with_background_audio_upload(mut self, background_audio: FileUpload) -> Self {
+        self.background_audio_upload = Some(background_audio);
+        self
+    }
+
     pub fn with_name(mut self, name: &str) -> Self {
         self.name = Some(name.to_string());
         self
@@ -277,6 +342,58 @@ impl DubbingBody {
         self.dubbing_studio = Some(dubbing_studio);
         self
     }
+
+    /// Leave the background audio out of the dub, which can improve quality for speeches
+    /// and monologues
+    pub fn with_drop_background_audio(mut self, drop_background_audio: bool) -> Self {
+        self.drop_background_audio = Some(drop_background_audio);
+        self
+    }
+
+    /// Censor profanities in the transcript with `[censored]`
+    pub fn with_use_profanity_filter(mut self, use_profanity_filter: bool) -> Self {
+        self.use_profanity_filter = Some(use_profanity_filter);
+        self
+    }
+
+    /// Use a library voice instead of cloning the speakers' voices
+    pub fn with_disable_voice_cloning(mut self, disable_voice_cloning: bool) -> Self {
+        self.disable_voice_cloning = Some(disable_voice_cloning);
+        self
+    }
+
+    /// Check the source, languages and time range before the request is sent
+    pub fn validate(&self) -> std::result::Result<(), DubbingError> {
+        // A CSV file accompanies the file in manual mode, so it isn't a source of its own
+        let sources = [
+            self.file.is_some(),
+            self.file_upload.is_some(),
+            self.source_url.is_some(),
+        ];
+        match sources.iter().filter(|set| **set).count() {
+            0 => return Err(DubbingError::NoSource),
+            1 => {}
+            _ => return Err(DubbingError::MultipleSources),
+        }
+        let manual = matches!(self.mode, Some(Mode::Manual));
+        if manual && self.dubbing_studio != Some(true) {
+            return Err(DubbingError::ManualModeWithoutStudio);
+        }
+        if self.target_lang.as_deref().is_none_or(str::is_empty) && !manual {
+            return Err(DubbingError::NoTargetLanguage);
+        }
+        for time in [self.start_time, self.end_time].into_iter().flatten() {
+            if !time.is_finite() || time < 0.0 {
+                return Err(DubbingError::InvalidTime(time));
+            }
+        }
+        if let (Some(start), Some(end)) = (self.start_time, self.end_time) {
+            if start >= end {
+                return Err(DubbingError::EmptyTimeRange { start, end });
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -304,7 +421,8 @@ impl Endpoint for GetDubbingProjectMetadata {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct GetDubbingProjectMetadataResponse {
     dubbing_id: String,
     name: String,
@@ -443,6 +561,9 @@ fn to_form(body: DubbingBody) -> Result<Form> {
         }
         form = form.part("file", part);
     }
+    if let Some(file) = body.file_upload {
+        form = form.part("file", file.to_part()?);
+    }
     if let Some(csv_file) = body.csv_file {
         form = form.text("csv_file", csv_file);
     }
@@ -492,6 +613,12 @@ fn to_form(body: DubbingBody) -> Result<Form> {
         }
         form = form.part("background_audio_file", part);
     }
+    if let Some(file) = body.foreground_audio_upload {
+        form = form.part("foreground_audio_file", file.to_part()?);
+    }
+    if let Some(file) = body.background_audio_upload {
+        form = form.part("background_audio_file", file.to_part()?);
+    }
     if let Some(name) = body.name {
         form = form.text("name", name);
     }
@@ -522,5 +649,14 @@ fn to_form(body: DubbingBody) -> Result<Form> {
     if let Some(dubbing_studio) = body.dubbing_studio {
         form = form.text("dubbing_studio", dubbing_studio.to_string());
     }
+    if let Some(drop_background_audio) = body.drop_background_audio {
+        form = form.text("drop_background_audio", drop_background_audio.to_string());
+    }
+    if let Some(use_profanity_filter) = body.use_profanity_filter {
+        form = form.text("use_profanity_filter", use_profanity_filter.to_string());
+    }
+    if let Some(disable_voice_cloning) = body.disable_voice_cloning {
+        form = form.text("disable_voice_cloning", disable_voice_cloning.to_string());
+    }
     Ok(form)
 }
@@ -6,10 +6,16 @@ use crate::endpoints::voice::VoiceSettings;
 
 const HISTORY_PATH: &str = "/v1/history";
 const AUDIO_PATH: &str = "/audio";
+const FEEDBACK_PATH: &str = "/feedback";
 const PAGE_SIZE_QUERY: &str = "page_size";
 const HISTORY_ITEM_IDS: &str = "history_item_ids";
 const START_AFTER_HISTORY_ITEM_ID_QUERY: &str = "start_after_history_item_id";
 const VOICE_ID_QUERY: &str = "voice_id";
+const MODEL_ID_QUERY: &str = "model_id";
+const SEARCH_QUERY: &str = "search";
+const SOURCE_QUERY: &str = "source";
+const DATE_AFTER_UNIX_QUERY: &str = "date_after_unix";
+const DATE_BEFORE_UNIX_QUERY: &str = "date_before_unix";
 
 #[derive(Clone, Debug)]
 pub struct DeleteHistoryItem(HistoryItemID);
@@ -38,6 +44,7 @@ impl Endpoint for DeleteHistoryItem {
 /// Download one or more history items.
 /// If one history item ID is provided, we will return a single audio file.
 /// If more than one history item IDs are provided, we will provide the history items packed into a .zip file.
+/// Use [unzip](crate::utils::unzip) to extract it.
 ///
 /// # Example
 /// ```no_run
@@ -189,6 +196,26 @@ impl Endpoint for GetAudio {
 ///     Ok(())
 /// }
 /// ```
+///
+/// ## Filtering
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     // Speech to speech items with one voice from the last week
+///     let week_ago = std::time::SystemTime::now()
+///         .duration_since(std::time::UNIX_EPOCH)?
+///         .as_secs() - 7 * 24 * 60 * 60;
+///     let query = HistoryQuery::default()
+///         .with_voice_id("some_voice_id")
+///         .with_source(HistorySource::STS)
+///         .with_date_after_unix(week_ago);
+///     let resp = c.hit(GetGeneratedItems::new(query)).await?;
+///     Ok(())
+/// }
+/// ```
 #[derive(Clone, Debug)]
 pub struct GetGeneratedItems(HistoryQuery);
 
@@ -239,43 +266,169 @@ impl Endpoint for GetHistoryItem {
     }
 }
 
+/// Send feedback on a history item
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = FeedbackBody::thumbs_down().with_feedback("The date was read out wrong");
+///     let resp = c.hit(SubmitHistoryItemFeedback::new("some_history_item_id", body)).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SubmitHistoryItemFeedback {
+    history_item_id: HistoryItemID,
+    body: FeedbackBody,
+}
+
+impl SubmitHistoryItemFeedback {
+    pub fn new<T: Into<String>>(history_item_id: T, body: FeedbackBody) -> Self {
+        Self {
+            history_item_id: HistoryItemID(history_item_id.into()),
+            body,
+        }
+    }
+}
+
+impl Endpoint for SubmitHistoryItemFeedback {
+    type ResponseBody = StatusResponseBody;
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(&format!(
+            "{}/{}{}",
+            HISTORY_PATH, self.history_item_id.0, FEEDBACK_PATH
+        ));
+        url
+    }
+}
+
+/// Feedback body for [SubmitHistoryItemFeedback]
+#[derive(Clone, Debug, Serialize)]
+pub struct FeedbackBody {
+    thumbs_up: bool,
+    feedback: String,
+}
+
+impl FeedbackBody {
+    pub fn thumbs_up() -> Self {
+        Self {
+            thumbs_up: true,
+            feedback: String::new(),
+        }
+    }
+    pub fn thumbs_down() -> Self {
+        Self {
+            thumbs_up: false,
+            feedback: String::new(),
+        }
+    }
+    /// Free text feedback on the item
+    pub fn with_feedback<T: Into<String>>(mut self, feedback: T) -> Self {
+        self.feedback = feedback.into();
+        self
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct HistoryQuery {
     pub page_size: Option<String>,
     pub start_after_history_item_id: Option<String>,
     pub voice_id: Option<String>,
+    pub model_id: Option<String>,
+    pub search: Option<String>,
+    pub source: Option<String>,
+    pub date_after_unix: Option<String>,
+    pub date_before_unix: Option<String>,
+}
+
+/// Which kind of generation a history item comes from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HistorySource {
+    /// Text to speech
+    TTS,
+    /// Speech to speech
+    STS,
+}
+
+impl HistorySource {
+    fn as_str(&self) -> &str {
+        match self {
+            HistorySource::TTS => "TTS",
+            HistorySource::STS => "STS",
+        }
+    }
 }
 
 impl HistoryQuery {
     pub fn with_page_size(mut self, page_size: u16) -> Self {
-        self.page_size = Some(format!("{}={}", PAGE_SIZE_QUERY, page_size));
+        self.page_size = Some(query_pair(PAGE_SIZE_QUERY, page_size));
         self
     }
     pub fn with_start_after_history_item_id(mut self, start_after_history_item_id: &str) -> Self {
-        self.start_after_history_item_id = Some(format!(
-            "{}={}",
-            START_AFTER_HISTORY_ITEM_ID_QUERY, start_after_history_item_id
+        self.start_after_history_item_id = Some(query_pair(
+            START_AFTER_HISTORY_ITEM_ID_QUERY,
+            start_after_history_item_id,
         ));
         self
     }
 
     pub fn with_voice_id<T: Into<String>>(mut self, voice_id: T) -> Self {
-        self.voice_id = Some(format!("{}={}", VOICE_ID_QUERY, voice_id.into()));
+        self.voice_id = Some(query_pair(VOICE_ID_QUERY, voice_id.into()));
+        self
+    }
+    pub fn with_model_id<T: Into<String>>(mut self, model_id: T) -> Self {
+        self.model_id = Some(query_pair(MODEL_ID_QUERY, model_id.into()));
+        self
+    }
+    /// Only items whose text contains `search`
+    pub fn with_search(mut self, search: &str) -> Self {
+        self.search = Some(query_pair(SEARCH_QUERY, search));
+        self
+    }
+    pub fn with_source(mut self, source: HistorySource) -> Self {
+        self.source = Some(query_pair(SOURCE_QUERY, source.as_str()));
+        self
+    }
+    /// Only items generated after `date_unix`, in Unix seconds
+    pub fn with_date_after_unix(mut self, date_unix: u64) -> Self {
+        self.date_after_unix = Some(query_pair(DATE_AFTER_UNIX_QUERY, date_unix));
+        self
+    }
+    /// Only items generated before `date_unix`, in Unix seconds
+    pub fn with_date_before_unix(mut self, date_unix: u64) -> Self {
+        self.date_before_unix = Some(query_pair(DATE_BEFORE_UNIX_QUERY, date_unix));
         self
     }
     pub fn join(&mut self) -> Option<String> {
         let mut result = String::new();
 
-        if let Some(value) = self.page_size.take() {
-            result.push_str(&value);
-        }
-        if let Some(value) = self.start_after_history_item_id.take() {
-            if !result.is_empty() {
-                result.push('&');
-            }
-            result.push_str(&value);
-        }
-        if let Some(value) = self.voice_id.take() {
+        for value in [
+            self.page_size.take(),
+            self.start_after_history_item_id.take(),
+            self.voice_id.take(),
+            self.model_id.take(),
+            self.search.take(),
+            self.source.take(),
+            self.date_after_unix.take(),
+            self.date_before_unix.take(),
+        ]
+        .into_iter()
+        .flatten()
+        {
             if !result.is_empty() {
                 result.push('&');
             }
@@ -289,7 +442,8 @@ impl HistoryQuery {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct GeneratedItems {
     history: Vec<HistoryItem>,
     last_history_item_id: String,
@@ -308,14 +462,15 @@ impl GeneratedItems {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct HistoryItem {
     history_item_id: String,
     request_id: String,
     voice_id: String,
     voice_name: String,
     voice_category: Option<String>,
-    model_id: Option<String>,
+    model_id: Option<Model>,
     text: String,
     date_unix: u64,
     character_count_change_from: u64,
@@ -344,7 +499,10 @@ impl HistoryItem {
         self.voice_category.as_deref()
     }
     pub fn model_id(&self) -> Option<&str> {
-        self.model_id.as_deref()
+        self.model_id.as_ref().map(Model::as_str)
+    }
+    pub fn model(&self) -> Option<&Model> {
+        self.model_id.as_ref()
     }
     pub fn text(&self) -> &str {
         &self.text
@@ -358,6 +516,11 @@ impl HistoryItem {
     pub fn character_count_change_to(&self) -> u64 {
         self.character_count_change_to
     }
+    /// The characters the generation used from the quota
+    pub fn character_count(&self) -> u64 {
+        self.character_count_change_to
+            .saturating_sub(self.character_count_change_from)
+    }
     pub fn content_type(&self) -> &str {
         &self.content_type
     }
@@ -378,7 +541,8 @@ impl HistoryItem {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Feedback {
     thumbs_up: bool,
     feedback: String,
@@ -415,3 +579,17 @@ impl Feedback {
         self.other
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_is_percent_encoded() {
+        let search = "rock & roll = café 日本";
+        let url = GetGeneratedItems::new(HistoryQuery::default().with_search(search)).url();
+        let pairs = url.query_pairs().collect::<Vec<_>>();
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0], ("search".into(), search.into()));
+    }
+}
@@ -1,8 +1,9 @@
-pub use crate::client::{Result, BASE_URL};
+pub use crate::client::{DefaultSettings, Result, BASE_URL};
 pub(crate) use crate::shared::identifiers::*;
 pub(crate) use crate::shared::path_segments::*;
 pub use crate::shared::query_params::*;
 pub use crate::shared::response_bodies::*;
+pub use crate::shared::uploads::*;
 pub use base64::prelude::{Engine, BASE64_STANDARD};
 pub use bytes::Bytes;
 pub use reqwest::{
@@ -18,9 +19,12 @@ pub mod history;
 pub mod models;
 pub mod projects;
 pub mod pronunciation;
+pub mod pvc;
 pub mod samples;
 pub mod sound_generation;
+pub mod speech_to_text;
 pub mod sts;
+pub mod text_to_dialogue;
 pub mod tts;
 pub mod user;
 pub mod voice;
@@ -32,20 +36,100 @@ pub mod audio_isolation;
 pub mod conversational_ai;
 pub mod voice_design;
 
+/// An API route that [ElevenLabsClient::hit](crate::ElevenLabsClient::hit) can send
+///
+/// Every endpoint in this crate implements it. Implement it yourself to call a route the
+/// crate doesn't cover yet, with all of the client's API key handling, base URL, proxy
+/// and error handling.
+///
+/// `url` is built on [BASE_URL]. The client swaps in its own base URL when one is set, so
+/// only the path and query of the URL matter. A response with an error status never
+/// reaches `response_body`, it is returned as an
+/// [ElevenLabsClientError](crate::error::ElevenLabsClientError) or
+/// [ElevenLabsServerError](crate::error::ElevenLabsServerError) instead.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::{Endpoint, Method, RequestBody, Response, Url, Value, BASE_URL};
+///
+/// struct RenameThing {
+///     thing_id: String,
+///     name: String,
+/// }
+///
+/// impl Endpoint for RenameThing {
+///     type ResponseBody = Value;
+///
+///     fn method(&self) -> Method {
+///         Method::PATCH
+///     }
+///     fn request_body(&self) -> Result<RequestBody> {
+///         Ok(RequestBody::Json(serde_json::json!({ "name": self.name })))
+///     }
+///     async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+///         Ok(resp.json().await?)
+///     }
+///     fn url(&self) -> Url {
+///         let mut url = BASE_URL.parse::<Url>().unwrap();
+///         url.set_path(&format!("/v1/things/{}", self.thing_id));
+///         url
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let endpoint = RenameThing {
+///         thing_id: "some_thing_id".to_string(),
+///         name: "New name".to_string(),
+///     };
+///     let resp = c.hit(endpoint).await?;
+///     println!("{:#?}", resp);
+///     Ok(())
+/// }
+/// ```
 #[allow(async_fn_in_trait)]
 pub trait Endpoint {
+    /// What [response_body](Endpoint::response_body) turns a successful response into
     type ResponseBody;
 
+    /// `GET`, `DELETE`, `POST`, `PATCH` or `PUT`
     fn method(&self) -> Method;
+    /// The body sent with `POST`, `PATCH` and `PUT` requests, ignored for the other methods
     fn request_body(&self) -> Result<RequestBody> {
         Ok(RequestBody::Empty)
     }
+    /// Read a successful response, e.g. with `resp.json()`, `resp.bytes()` or
+    /// [audio_stream]
     async fn response_body(self, resp: Response) -> Result<Self::ResponseBody>;
+    /// The URL of the request, on [BASE_URL]
     fn url(&self) -> Url;
+    /// Fill in anything not set on the endpoint from the client's [DefaultSettings]
+    fn apply_defaults(&mut self, _defaults: &DefaultSettings) {}
 }
 
+/// The body of a request, see [Endpoint::request_body]
 pub enum RequestBody {
+    /// Sent as `application/json`
     Json(Value),
+    /// Sent as `multipart/form-data`
     Multipart(Form),
+    /// No body, an error for `POST`, `PATCH` and `PUT` requests
     Empty,
 }
+
+/// The body of a streaming audio response, with JSON error frames turned into
+/// [ApiError](crate::error::ApiError)s
+pub fn audio_stream(
+    resp: Response,
+) -> impl futures_util::Stream<Item = Result<Bytes>> + Send {
+    use futures_util::StreamExt;
+    resp.bytes_stream().map(|chunk| {
+        let chunk = chunk?;
+        match crate::error::ApiError::from_frame(&chunk) {
+            Some(error) => Err(error.into()),
+            None => Ok(chunk),
+        }
+    })
+}
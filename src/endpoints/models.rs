@@ -2,16 +2,40 @@
 #![allow(dead_code)]
 use crate::client::{Result, BASE_URL};
 use crate::endpoints::Endpoint;
+use crate::shared::identifiers::Language;
 use reqwest::Response;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 const MODELS_PATH: &str = "v1/models";
 
+/// Get the available models
+///
+/// The response is a [Models] list that can be filtered by capability
+/// to pick a model at runtime.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let models = c.hit(GetModels).await?;
+///     let model = models
+///         .supporting_language(&Language::Japanese)
+///         .into_iter()
+///         .find(|m| m.can_use_style())
+///         .expect("a Japanese model with style support");
+///     println!("{}: {}", model.model_id(), model.max_characters());
+///     let body = TextToSpeechBody::new("こんにちは", model.to_model());
+///     Ok(())
+/// }
+/// ```
 #[derive(Clone, Debug)]
 pub struct GetModels;
 
 impl Endpoint for GetModels {
-    type ResponseBody = ModelResponse;
+    type ResponseBody = Models;
 
     fn method(&self) -> reqwest::Method {
         reqwest::Method::GET
@@ -26,9 +50,71 @@ impl Endpoint for GetModels {
     }
 }
 
-type ModelResponse = Vec<Model>;
+/// The models returned by [GetModels]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Models(Vec<Model>);
 
-#[derive(Clone, Debug, Deserialize)]
+impl Models {
+    /// Find a model by its ID
+    pub fn find(&self, model_id: &str) -> Option<&Model> {
+        self.0.iter().find(|m| m.model_id == model_id)
+    }
+    /// Models that support `language`
+    pub fn supporting_language(&self, language: &Language) -> Vec<&Model> {
+        self.0
+            .iter()
+            .filter(|m| m.supports_language(language))
+            .collect()
+    }
+    /// Models that accept at least `characters` characters in a single request
+    pub fn with_max_characters(&self, characters: u32) -> Vec<&Model> {
+        self.0
+            .iter()
+            .filter(|m| m.max_characters() >= characters)
+            .collect()
+    }
+    /// The text to speech model that accepts the longest text per request
+    pub fn max_characters(&self) -> Option<&Model> {
+        self.0
+            .iter()
+            .filter(|m| m.can_do_text_to_speech)
+            .max_by_key(|m| m.max_characters())
+    }
+    pub fn iter(&self) -> std::slice::Iter<'_, Model> {
+        self.0.iter()
+    }
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn into_inner(self) -> Vec<Model> {
+        self.0
+    }
+}
+
+impl IntoIterator for Models {
+    type Item = Model;
+    type IntoIter = std::vec::IntoIter<Model>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Models {
+    type Item = &'a Model;
+    type IntoIter = std::slice::Iter<'a, Model>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Model {
     model_id: String,
     name: String,
@@ -44,11 +130,84 @@ pub struct Model {
     max_characters_request_free_user: f32,
     max_characters_request_subscribed_user: f32,
     maximum_text_length_per_request: f32,
-    languages: Vec<Language>,
+    languages: Vec<ModelLanguage>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-struct Language {
+impl Model {
+    pub fn model_id(&self) -> &str {
+        &self.model_id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+    pub fn can_be_finetuned(&self) -> bool {
+        self.can_be_finetuned
+    }
+    pub fn can_do_text_to_speech(&self) -> bool {
+        self.can_do_text_to_speech
+    }
+    pub fn can_do_voice_conversion(&self) -> bool {
+        self.can_do_voice_conversion
+    }
+    pub fn can_use_style(&self) -> bool {
+        self.can_use_style
+    }
+    pub fn can_use_speaker_boost(&self) -> bool {
+        self.can_use_speaker_boost
+    }
+    pub fn serves_pro_voices(&self) -> bool {
+        self.serves_pro_voices
+    }
+    pub fn token_cost_factor(&self) -> f32 {
+        self.token_cost_factor
+    }
+    pub fn requires_alpha_access(&self) -> bool {
+        self.requires_alpha_access
+    }
+    pub fn max_characters_request_free_user(&self) -> f32 {
+        self.max_characters_request_free_user
+    }
+    pub fn max_characters_request_subscribed_user(&self) -> f32 {
+        self.max_characters_request_subscribed_user
+    }
+    pub fn maximum_text_length_per_request(&self) -> f32 {
+        self.maximum_text_length_per_request
+    }
+    /// The maximum number of characters in a single request
+    pub fn max_characters(&self) -> u32 {
+        self.maximum_text_length_per_request as u32
+    }
+    pub fn languages(&self) -> &Vec<ModelLanguage> {
+        &self.languages
+    }
+    pub fn supports_language(&self, language: &Language) -> bool {
+        self.languages.iter().any(|l| l.language() == *language)
+    }
+    /// The model identifier to use in request bodies
+    pub fn to_model(&self) -> crate::shared::identifiers::Model {
+        self.model_id.parse().unwrap_or_else(|never| match never {})
+    }
+}
+
+/// A language a model supports
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ModelLanguage {
     language_id: String,
     name: String,
 }
+
+impl ModelLanguage {
+    pub fn language_id(&self) -> &str {
+        &self.language_id
+    }
+    pub fn language(&self) -> Language {
+        Language::from_iso(&self.language_id)
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
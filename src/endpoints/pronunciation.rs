@@ -49,16 +49,65 @@ impl AddFromFile {
 /// ```
 #[derive(Clone, Debug)]
 pub struct AddFromFileBody {
-    file: String,
+    file: DictionaryFile,
     name: String,
     description: Option<String>,
     workspace_access: Option<String>,
 }
 
+/// Where the lexicon uploaded by [AddFromFile] comes from
+#[derive(Clone, Debug)]
+enum DictionaryFile {
+    Path(String),
+    Pls(String),
+    Upload(FileUpload),
+}
+
 impl AddFromFileBody {
     pub fn new(file: &str, name: &str) -> Self {
         Self {
-            file: file.to_string(),
+            file: DictionaryFile::Path(file.to_string()),
+            name: name.to_string(),
+            description: None,
+            workspace_access: None,
+        }
+    }
+
+    /// Build the dictionary from rules instead of a `.pls` file on disk.
+    ///
+    /// A PLS lexicon is generated in memory from the rules and uploaded in its place.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    /// use elevenlabs_rs::endpoints::pronunciation::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///    let c = ElevenLabsClient::default()?;
+    ///    let rules = vec![
+    ///        Rule::new_alias("TTS", "text to speech"),
+    ///        Rule::new_phoneme("tomato", "/tə'meɪtoʊ/", "ipa"),
+    ///    ];
+    ///    let body = AddFromFileBody::from_rules("acronyms", rules);
+    ///    let resp = c.hit(AddFromFile::new(body)).await?;
+    ///    println!("{:?}", resp);
+    ///    Ok(())
+    /// }
+    /// ```
+    pub fn from_rules(name: &str, rules: Vec<Rule>) -> Self {
+        Self {
+            file: DictionaryFile::Pls(to_pls(&rules)),
+            name: name.to_string(),
+            description: None,
+            workspace_access: None,
+        }
+    }
+
+    /// Upload a `.pls` lexicon that is already in memory
+    pub fn from_upload(file: FileUpload, name: &str) -> Self {
+        Self {
+            file: DictionaryFile::Upload(file),
             name: name.to_string(),
             description: None,
             workspace_access: None,
@@ -77,8 +126,12 @@ impl AddFromFileBody {
 
     fn to_form(&self) -> Result<Form> {
         let mut form = Form::new();
-        let file = std::fs::read(self.file.clone())?;
-        form = form.part("file", Part::bytes(file).file_name("file"));
+        let part = match &self.file {
+            DictionaryFile::Path(path) => Part::bytes(std::fs::read(path)?).file_name("file"),
+            DictionaryFile::Pls(pls) => Part::text(pls.clone()).file_name("dictionary.pls"),
+            DictionaryFile::Upload(upload) => upload.to_part()?,
+        };
+        form = form.part("file", part);
         form = form.text("name", self.name.clone());
         if let Some(description) = &self.description {
             form = form.text("description", description.clone());
@@ -113,7 +166,8 @@ impl Endpoint for AddFromFile {
 }
 
 /// Add from file response
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct AddFromFileResponse {
     id: String,
     name: String,
@@ -232,6 +286,59 @@ impl Rule {
     }
 }
 
+/// Render rules as a PLS lexicon document
+fn to_pls(rules: &[Rule]) -> String {
+    let mut pls = String::from(concat!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+        "<lexicon version=\"1.0\"\n",
+        "      xmlns=\"http://www.w3.org/2005/01/pronunciation-lexicon\"\n",
+        "      xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\"\n",
+        "      xsi:schemaLocation=\"http://www.w3.org/2005/01/pronunciation-lexicon\n",
+        "        http://www.w3.org/TR/2007/CR-pronunciation-lexicon-20071212/pls.xsd\"\n",
+        "      alphabet=\"ipa\" xml:lang=\"en-US\">\n",
+    ));
+    for rule in rules {
+        pls.push_str("  <lexeme>\n");
+        match rule {
+            Rule::Alias {
+                string_to_replace,
+                alias,
+                ..
+            } => {
+                pls.push_str(&format!(
+                    "    <grapheme>{}</grapheme>\n    <alias>{}</alias>\n",
+                    escape_xml(string_to_replace),
+                    escape_xml(alias)
+                ));
+            }
+            Rule::Phoneme {
+                string_to_replace,
+                phoneme,
+                alphabet,
+                ..
+            } => {
+                pls.push_str(&format!(
+                    "    <grapheme>{}</grapheme>\n    <phoneme alphabet=\"{}\">{}</phoneme>\n",
+                    escape_xml(string_to_replace),
+                    escape_xml(alphabet),
+                    escape_xml(phoneme)
+                ));
+            }
+        }
+        pls.push_str("  </lexeme>\n");
+    }
+    pls.push_str("</lexicon>\n");
+    pls
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
 impl Endpoint for AddRules {
     type ResponseBody = RulesResponse;
 
@@ -258,7 +365,8 @@ impl Endpoint for AddRules {
 }
 
 /// Add rules response
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct RulesResponse {
     id: String,
     version_id: String,
@@ -315,12 +423,12 @@ pub struct GetDictionariesQuery {
 
 impl GetDictionariesQuery {
     pub fn with_page_size(mut self, page_size: i32) -> Self {
-        self.page_size = Some(format!("{}={}", PAGE_SIZE_QUERY, page_size));
+        self.page_size = Some(query_pair(PAGE_SIZE_QUERY, page_size));
         self
     }
 
     pub fn with_cursor(mut self, cursor: &str) -> Self {
-        self.cursor = Some(format!("{}={}", CURSOR_QUERY, cursor));
+        self.cursor = Some(query_pair(CURSOR_QUERY, cursor));
         self
     }
 
@@ -362,7 +470,8 @@ impl Endpoint for GetDictionaries {
 }
 
 /// Get dictionaries response
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct GetDictionariesResponse {
     pronunciation_dictionaries: Vec<PronunciationDictionary>,
     next_cursor: Option<String>,
@@ -384,7 +493,8 @@ impl GetDictionariesResponse {
 }
 
 /// Pronunciation dictionary
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PronunciationDictionary {
     id: String,
     latest_version_id: String,
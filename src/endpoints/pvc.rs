@@ -0,0 +1,257 @@
+#![allow(dead_code)]
+//! The professional voice cloning (PVC) verification endpoints
+//!
+//! A PVC voice has to be verified before it can be fine-tuned, either by reading a captcha
+//! or by requesting manual verification. The API has no verification status endpoint of
+//! its own, the state is in the [FineTuning] of the voice returned by [GetVoice].
+//! [GetVerificationStatus] fetches just that.
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/voices/pvc/verification/request) for more information.
+use super::*;
+use crate::endpoints::voice::{FineTuning, VoiceResponseBody};
+
+const VOICES_PATH: &str = "/v1/voices";
+const PVC_PATH: &str = "/v1/voices/pvc";
+const VERIFICATION_PATH: &str = "/verification";
+const CAPTCHA_PATH: &str = "/captcha";
+
+/// Request manual verification of a PVC voice by uploading documents
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let consent = FileUpload::from_path("consent.mp3")?;
+///     let body = RequestManualVerificationBody::new(vec![consent])
+///         .with_extra_text("Recorded consent of the voice owner");
+///     let resp = c.hit(RequestManualVerification::new("some_voice_id", body)).await?;
+///     println!("{:#?}", resp);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RequestManualVerification {
+    voice_id: VoiceID,
+    body: RequestManualVerificationBody,
+}
+
+impl RequestManualVerification {
+    pub fn new<T: Into<String>>(voice_id: T, body: RequestManualVerificationBody) -> Self {
+        RequestManualVerification {
+            voice_id: VoiceID::from(voice_id.into()),
+            body,
+        }
+    }
+}
+
+/// Request manual verification body
+#[derive(Clone, Debug)]
+pub struct RequestManualVerificationBody {
+    files: Vec<FileUpload>,
+    extra_text: Option<String>,
+}
+
+impl RequestManualVerificationBody {
+    pub fn new(files: Vec<FileUpload>) -> Self {
+        RequestManualVerificationBody {
+            files,
+            extra_text: None,
+        }
+    }
+    /// Extra text to help the reviewer verify the voice
+    pub fn with_extra_text(mut self, extra_text: &str) -> Self {
+        self.extra_text = Some(extra_text.to_string());
+        self
+    }
+    fn to_form(&self) -> Result<Form> {
+        let mut form = Form::new();
+        for file in &self.files {
+            form = form.part("files", file.to_part()?);
+        }
+        if let Some(extra_text) = &self.extra_text {
+            form = form.text("extra_text", extra_text.clone());
+        }
+        Ok(form)
+    }
+}
+
+impl Endpoint for RequestManualVerification {
+    type ResponseBody = StatusResponseBody;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Multipart(self.body.to_form()?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(&format!(
+            "{}/{}{}",
+            PVC_PATH, self.voice_id.0, VERIFICATION_PATH
+        ));
+        url
+    }
+}
+
+/// Get the verification state of a PVC voice: its verification attempts, whether manual
+/// verification was requested, and the attempts left
+///
+/// This is [GetVoice] keeping only the voice's [FineTuning], which is `None` for voices
+/// that cannot be fine-tuned.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     if let Some(fine_tuning) = c.hit(GetVerificationStatus::new("some_voice_id")).await? {
+///         println!("{:?} attempts", fine_tuning.verification_attempts_count());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetVerificationStatus(VoiceID);
+
+impl GetVerificationStatus {
+    pub fn new<T: Into<String>>(voice_id: T) -> Self {
+        GetVerificationStatus(VoiceID::from(voice_id.into()))
+    }
+}
+
+impl Endpoint for GetVerificationStatus {
+    type ResponseBody = Option<FineTuning>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        let voice = resp.json::<VoiceResponseBody>().await?;
+        Ok(voice.get_fine_tuning().cloned())
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(&format!("{}/{}", VOICES_PATH, self.0 .0));
+        url
+    }
+}
+
+/// Get the captcha to read aloud for verifying a PVC voice
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let captcha = c.hit(GetCaptcha::new("some_voice_id")).await?;
+///     if let Some(captcha) = captcha.as_str() {
+///         println!("{captcha}");
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetCaptcha(VoiceID);
+
+impl GetCaptcha {
+    pub fn new<T: Into<String>>(voice_id: T) -> Self {
+        GetCaptcha(VoiceID::from(voice_id.into()))
+    }
+}
+
+impl Endpoint for GetCaptcha {
+    type ResponseBody = CaptchaResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(&format!("{}/{}{}", PVC_PATH, self.0 .0, CAPTCHA_PATH));
+        url
+    }
+}
+
+/// The captcha of a PVC voice
+///
+/// The API does not document the captcha's shape, so it is kept as sent.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct CaptchaResponse(Value);
+
+impl CaptchaResponse {
+    /// The captcha, when the API sends it as a string
+    pub fn as_str(&self) -> Option<&str> {
+        self.0.as_str()
+    }
+    pub fn value(&self) -> &Value {
+        &self.0
+    }
+    pub fn into_value(self) -> Value {
+        self.0
+    }
+}
+
+/// Submit a recording of the captcha being read to verify a PVC voice
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let recording = FileUpload::from_path("captcha_reading.mp3")?;
+///     let resp = c.hit(VerifyCaptcha::new("some_voice_id", recording)).await?;
+///     println!("{:#?}", resp);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct VerifyCaptcha {
+    voice_id: VoiceID,
+    recording: FileUpload,
+}
+
+impl VerifyCaptcha {
+    pub fn new<T: Into<String>>(voice_id: T, recording: FileUpload) -> Self {
+        VerifyCaptcha {
+            voice_id: VoiceID::from(voice_id.into()),
+            recording,
+        }
+    }
+}
+
+impl Endpoint for VerifyCaptcha {
+    type ResponseBody = StatusResponseBody;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        let form = Form::new().part("recording", self.recording.to_part()?);
+        Ok(RequestBody::Multipart(form))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(&format!("{}/{}{}", PVC_PATH, self.voice_id.0, CAPTCHA_PATH));
+        url
+    }
+}
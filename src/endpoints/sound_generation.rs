@@ -1,8 +1,13 @@
 //! The sound generation endpoint.
 #[allow(dead_code)]
 use super::*;
+use crate::error::SoundGenerationError;
+use futures_util::Stream;
+use std::pin::Pin;
 
 const SOUND_GENERATION_PATH: &str = "/v1/sound-generation";
+const MIN_DURATION: f64 = 0.5;
+const MAX_DURATION: f64 = 22.0;
 
 /// Sound Generation endpoint
 ///
@@ -33,6 +38,25 @@ pub struct SoundGeneration(SoundGenerationBody);
 pub struct SoundGenerationBody {
     text: String,
     generation_settings: GenerationSettings,
+    #[serde(rename = "loop", skip_serializing_if = "Option::is_none")]
+    loop_: Option<bool>,
+}
+
+impl SoundGenerationBody {
+    fn new(text: &str, generation_settings: GenerationSettings) -> Self {
+        SoundGenerationBody {
+            text: text.to_string(),
+            generation_settings,
+            loop_: None,
+        }
+    }
+    /// Check the text and settings before the request is sent
+    pub fn validate(&self) -> std::result::Result<(), SoundGenerationError> {
+        if self.text.trim().is_empty() {
+            return Err(SoundGenerationError::EmptyText);
+        }
+        self.generation_settings.validate()
+    }
 }
 
 /// `duration_seconds` expected to be greater or equal to 0.5 and less or equal to 22,
@@ -46,10 +70,12 @@ pub struct GenerationSettings {
 
 impl SoundGeneration {
     pub fn new(text: &str, generation_settings: GenerationSettings) -> Self {
-        SoundGeneration(SoundGenerationBody {
-            text: text.to_string(),
-            generation_settings,
-        })
+        SoundGeneration(SoundGenerationBody::new(text, generation_settings))
+    }
+    /// Generate a sound that loops smoothly
+    pub fn with_loop(mut self, loop_: bool) -> Self {
+        self.0.loop_ = Some(loop_);
+        self
     }
 }
 
@@ -61,6 +87,24 @@ impl GenerationSettings {
             prompt_influence,
         }
     }
+    /// Check that the duration and prompt influence are in range.
+    ///
+    /// The duration is only checked when auto duration is off, as it is ignored otherwise.
+    pub fn validate(&self) -> std::result::Result<(), SoundGenerationError> {
+        if !self.use_auto_duration
+            && !(MIN_DURATION..=MAX_DURATION).contains(&self.duration_seconds)
+        {
+            return Err(SoundGenerationError::DurationOutOfRange(
+                self.duration_seconds,
+            ));
+        }
+        if !(0.0..=1.0).contains(&self.prompt_influence) {
+            return Err(SoundGenerationError::PromptInfluenceOutOfRange(
+                self.prompt_influence,
+            ));
+        }
+        Ok(())
+    }
 }
 
 impl Endpoint for SoundGeneration {
@@ -71,6 +115,7 @@ impl Endpoint for SoundGeneration {
     }
 
     fn request_body(&self) -> Result<RequestBody> {
+        self.0.validate()?;
         Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
     }
 
@@ -84,3 +129,60 @@ impl Endpoint for SoundGeneration {
         url
     }
 }
+
+/// Sound Generation endpoint that streams the audio as it is generated
+///
+/// # Example
+///
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::stream_audio;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///    let c = ElevenLabsClient::default()?;
+///    let settings = GenerationSettings::new(false, 20.0, 0.3);
+///    let endpoint = SoundGenerationStream::new("Rain on a tin roof", settings).with_loop(true);
+///    let stream = c.hit(endpoint).await?;
+///    stream_audio(stream).await?;
+///    Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SoundGenerationStream(SoundGenerationBody);
+
+impl SoundGenerationStream {
+    pub fn new(text: &str, generation_settings: GenerationSettings) -> Self {
+        SoundGenerationStream(SoundGenerationBody::new(text, generation_settings))
+    }
+    /// Generate a sound that loops smoothly
+    pub fn with_loop(mut self, loop_: bool) -> Self {
+        self.0.loop_ = Some(loop_);
+        self
+    }
+}
+
+type SoundGenerationStreamResponse = Pin<Box<dyn Stream<Item = Result<Bytes>>>>;
+
+impl Endpoint for SoundGenerationStream {
+    type ResponseBody = SoundGenerationStreamResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn request_body(&self) -> Result<RequestBody> {
+        self.0.validate()?;
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(Box::pin(audio_stream(resp)))
+    }
+
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(SOUND_GENERATION_PATH);
+        url
+    }
+}
@@ -0,0 +1,794 @@
+//! The speech to text endpoints
+//!
+//! [SpeechToText] transcribes a file and waits for the transcript. For long files,
+//! [SpeechToTextAsync] returns as soon as the file is accepted; the transcript is then
+//! delivered to the workspace's speech to text webhook, or can be polled with
+//! [GetTranscript].
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/speech-to-text/convert) for more information.
+#![allow(dead_code)]
+use super::*;
+use crate::error::Error;
+use reqwest::Body;
+use std::sync::{Arc, Mutex};
+use tokio::io::AsyncRead;
+
+const SPEECH_TO_TEXT_PATH: &str = "/v1/speech-to-text";
+const TRANSCRIPTS_PATH: &str = "/transcripts";
+const DEFAULT_MODEL_ID: &str = "scribe_v1";
+
+/// Speech to Text endpoint
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let file = FileUpload::from_path("interview.mp3")?;
+///     let body = SpeechToTextBody::new(file)
+///         .with_diarize(true)
+///         .with_timestamps_granularity(TimestampsGranularity::Word);
+///     let transcript = c.hit(SpeechToText::new(body)).await?;
+///     for word in transcript.words() {
+///         println!("{:?}: {}", word.speaker_id(), word.text());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpeechToText(SpeechToTextBody);
+
+impl SpeechToText {
+    pub fn new(body: SpeechToTextBody) -> Self {
+        SpeechToText(body)
+    }
+}
+
+impl Endpoint for SpeechToText {
+    type ResponseBody = Transcript;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Multipart(self.0.to_form(false)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(SPEECH_TO_TEXT_PATH);
+        url
+    }
+}
+
+/// Speech to Text endpoint that delivers the transcript to a webhook
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = SpeechToTextBody::new(FileUpload::from_path("lecture.mp3")?);
+///     let submitted = c.hit(SpeechToTextAsync::new(body)).await?;
+///
+///     // Without a webhook handler, poll for the result
+///     let transcription_id = submitted.transcription_id().expect("a transcription id");
+///     let transcript = c.hit(GetTranscript::new(transcription_id)).await?;
+///     println!("{}", transcript.text());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpeechToTextAsync(SpeechToTextBody);
+
+impl SpeechToTextAsync {
+    pub fn new(body: SpeechToTextBody) -> Self {
+        SpeechToTextAsync(body)
+    }
+}
+
+impl Endpoint for SpeechToTextAsync {
+    type ResponseBody = TranscriptionSubmitted;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Multipart(self.0.to_form(true)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(SPEECH_TO_TEXT_PATH);
+        url
+    }
+}
+
+/// Get the transcript of an earlier transcription
+#[derive(Clone, Debug)]
+pub struct GetTranscript {
+    transcription_id: String,
+}
+
+impl GetTranscript {
+    pub fn new(transcription_id: &str) -> Self {
+        GetTranscript {
+            transcription_id: transcription_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for GetTranscript {
+    type ResponseBody = Transcript;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(&format!(
+            "{}{}/{}",
+            SPEECH_TO_TEXT_PATH, TRANSCRIPTS_PATH, self.transcription_id
+        ));
+        url
+    }
+}
+
+/// Speech to Text body for all speech to text endpoints
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     // Streamed from disk instead of read into memory first
+///     let file = tokio::fs::File::open("all_hands.mp3").await?;
+///     let body = SpeechToTextBody::from_async_read(file, "all_hands.mp3", "audio/mpeg");
+///     let submitted = c.hit(SpeechToTextAsync::new(body)).await?;
+///
+///     // Or fetched by ElevenLabs from cloud storage
+///     let body = SpeechToTextBody::from_cloud_url("https://storage.example.com/podcast.mp3");
+///     let transcript = c.hit(SpeechToText::new(body)).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpeechToTextBody {
+    audio: AudioSource,
+    model_id: String,
+    language_code: Option<String>,
+    tag_audio_events: Option<bool>,
+    num_speakers: Option<u32>,
+    timestamps_granularity: Option<TimestampsGranularity>,
+    diarize: Option<bool>,
+}
+
+impl SpeechToTextBody {
+    /// A body using the `scribe_v1` model
+    pub fn new(file: FileUpload) -> Self {
+        Self::with_source(AudioSource::Upload(file))
+    }
+    /// A body streaming the audio from `reader` as it is uploaded, for files too large
+    /// to hold in memory.
+    ///
+    /// The stream can only be sent once, so a request that is retried with another API
+    /// key fails with [Error::UploadStreamConsumed].
+    pub fn from_async_read<R, N, M>(reader: R, file_name: N, mime_type: M) -> Self
+    where
+        R: AsyncRead + Send + Unpin + 'static,
+        N: Into<String>,
+        M: Into<String>,
+    {
+        Self::with_source(AudioSource::Stream {
+            body: Arc::new(Mutex::new(Some(read_body(reader)))),
+            file_name: file_name.into(),
+            mime_type: mime_type.into(),
+        })
+    }
+    /// A body for audio that ElevenLabs downloads from `url`, e.g. a signed S3 or GCS URL
+    pub fn from_cloud_url<T: Into<String>>(url: T) -> Self {
+        Self::with_source(AudioSource::CloudStorageUrl(url.into()))
+    }
+    fn with_source(audio: AudioSource) -> Self {
+        SpeechToTextBody {
+            audio,
+            model_id: DEFAULT_MODEL_ID.to_string(),
+            language_code: None,
+            tag_audio_events: None,
+            num_speakers: None,
+            timestamps_granularity: None,
+            diarize: None,
+        }
+    }
+    pub fn with_model_id(mut self, model_id: &str) -> Self {
+        self.model_id = model_id.to_string();
+        self
+    }
+    /// An ISO-639-1 or ISO-639-3 language code, detected automatically when not set
+    pub fn with_language_code(mut self, language_code: &str) -> Self {
+        self.language_code = Some(language_code.to_string());
+        self
+    }
+    /// Tag audio events such as `(laughter)` in the transcript
+    pub fn with_tag_audio_events(mut self, tag_audio_events: bool) -> Self {
+        self.tag_audio_events = Some(tag_audio_events);
+        self
+    }
+    /// The maximum number of speakers, at most 32
+    pub fn with_num_speakers(mut self, num_speakers: u32) -> Self {
+        self.num_speakers = Some(num_speakers);
+        self
+    }
+    pub fn with_timestamps_granularity(mut self, granularity: TimestampsGranularity) -> Self {
+        self.timestamps_granularity = Some(granularity);
+        self
+    }
+    /// Annotate which speaker is talking
+    pub fn with_diarize(mut self, diarize: bool) -> Self {
+        self.diarize = Some(diarize);
+        self
+    }
+    fn to_form(&self, webhook: bool) -> Result<Form> {
+        let mut form = Form::new().text("model_id", self.model_id.clone());
+        form = match &self.audio {
+            AudioSource::Upload(file) => form.part("file", file.to_part()?),
+            AudioSource::Stream {
+                body,
+                file_name,
+                mime_type,
+            } => {
+                let body = body
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .take()
+                    .ok_or(Box::new(Error::UploadStreamConsumed))?;
+                let part = Part::stream(body)
+                    .file_name(file_name.clone())
+                    .mime_str(mime_type)?;
+                form.part("file", part)
+            }
+            AudioSource::CloudStorageUrl(url) => form.text("cloud_storage_url", url.clone()),
+        };
+        if let Some(language_code) = &self.language_code {
+            form = form.text("language_code", language_code.clone());
+        }
+        if let Some(tag_audio_events) = self.tag_audio_events {
+            form = form.text("tag_audio_events", tag_audio_events.to_string());
+        }
+        if let Some(num_speakers) = self.num_speakers {
+            form = form.text("num_speakers", num_speakers.to_string());
+        }
+        if let Some(granularity) = &self.timestamps_granularity {
+            form = form.text("timestamps_granularity", granularity.as_str());
+        }
+        if let Some(diarize) = self.diarize {
+            form = form.text("diarize", diarize.to_string());
+        }
+        if webhook {
+            form = form.text("webhook", "true");
+        }
+        Ok(form)
+    }
+}
+
+/// Where the audio to transcribe comes from
+#[derive(Clone, Debug)]
+enum AudioSource {
+    Upload(FileUpload),
+    /// Taken out when the form is built, as a stream can only be sent once
+    Stream {
+        body: Arc<Mutex<Option<Body>>>,
+        file_name: String,
+        mime_type: String,
+    },
+    CloudStorageUrl(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TimestampsGranularity {
+    None,
+    Word,
+    Character,
+}
+
+impl TimestampsGranularity {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TimestampsGranularity::None => "none",
+            TimestampsGranularity::Word => "word",
+            TimestampsGranularity::Character => "character",
+        }
+    }
+}
+
+/// The response of [SpeechToTextAsync]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TranscriptionSubmitted {
+    message: String,
+    request_id: String,
+    transcription_id: Option<String>,
+}
+
+impl TranscriptionSubmitted {
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+    pub fn request_id(&self) -> &str {
+        &self.request_id
+    }
+    pub fn transcription_id(&self) -> Option<&str> {
+        self.transcription_id.as_deref()
+    }
+}
+
+/// A transcript, as returned by [SpeechToText] and [GetTranscript] and delivered by the
+/// speech to text webhook
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Transcript {
+    language_code: String,
+    language_probability: f32,
+    text: String,
+    #[serde(default)]
+    words: Vec<Word>,
+    transcription_id: Option<String>,
+    additional_formats: Option<Vec<Value>>,
+}
+
+impl Transcript {
+    /// The transcript in the data of a speech to text completion webhook
+    ///
+    /// The data either is the transcript, or holds it under `transcription`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::webhooks::{verify_and_parse, WebhookEvent};
+    /// use elevenlabs_rs::Transcript;
+    /// use reqwest::header::HeaderMap;
+    ///
+    /// fn handle(headers: &HeaderMap, body: &[u8], secret: &str) -> elevenlabs_rs::Result<()> {
+    ///     if let WebhookEvent::SpeechToTextTranscription(payload) =
+    ///         verify_and_parse::<WebhookEvent>(headers, body, secret)?
+    ///     {
+    ///         let transcript = Transcript::from_webhook(payload.data())?;
+    ///         println!("{}", transcript.text());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_webhook(data: &Value) -> Result<Transcript> {
+        let transcript = data.get("transcription").unwrap_or(data);
+        Ok(serde_json::from_value(transcript.clone())?)
+    }
+    pub fn language_code(&self) -> &str {
+        &self.language_code
+    }
+    pub fn language_probability(&self) -> f32 {
+        self.language_probability
+    }
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    pub fn words(&self) -> &[Word] {
+        &self.words
+    }
+    pub fn transcription_id(&self) -> Option<&str> {
+        self.transcription_id.as_deref()
+    }
+    pub fn additional_formats(&self) -> Option<&Vec<Value>> {
+        self.additional_formats.as_ref()
+    }
+}
+
+/// A word, space or audio event in a transcript
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Word {
+    text: String,
+    start: Option<f32>,
+    end: Option<f32>,
+    r#type: WordType,
+    speaker_id: Option<String>,
+    logprob: Option<f32>,
+    characters: Option<Vec<Character>>,
+}
+
+impl Word {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    pub fn start(&self) -> Option<f32> {
+        self.start
+    }
+    pub fn end(&self) -> Option<f32> {
+        self.end
+    }
+    pub fn r#type(&self) -> &WordType {
+        &self.r#type
+    }
+    pub fn speaker_id(&self) -> Option<&str> {
+        self.speaker_id.as_deref()
+    }
+    pub fn logprob(&self) -> Option<f32> {
+        self.logprob
+    }
+    pub fn characters(&self) -> Option<&Vec<Character>> {
+        self.characters.as_ref()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum WordType {
+    Word,
+    Spacing,
+    AudioEvent,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Character {
+    text: String,
+    start: Option<f32>,
+    end: Option<f32>,
+}
+
+impl Character {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    pub fn start(&self) -> Option<f32> {
+        self.start
+    }
+    pub fn end(&self) -> Option<f32> {
+        self.end
+    }
+}
+
+pub mod realtime {
+    //! Realtime speech to text over a websocket
+    //!
+    //! Audio is sent in chunks as it is captured, and partial transcripts come back while
+    //! the speaker is still talking. Committed transcripts are final.
+    //!
+    //! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/speech-to-text/v-1-speech-to-text-realtime) for more information.
+    use super::*;
+    use crate::error::ApiError;
+    use crate::utils::transcode::AudioEncoding;
+    use futures_util::Stream;
+
+    const WS_BASE_URL: &str = "wss://api.elevenlabs.io";
+    const REALTIME_PATH: &str = "/v1/speech-to-text/realtime";
+    const DEFAULT_REALTIME_MODEL_ID: &str = "scribe_v2_realtime";
+
+    /// Realtime Speech to Text websocket endpoint
+    ///
+    /// The audio stream yields raw chunks in the endpoint's audio encoding, 16kHz PCM by
+    /// default. Once it ends, the remaining audio is committed and the response stream ends
+    /// after its committed transcript. With [CommitStrategy::Vad] the server may commit a
+    /// transcript of its own while the final commit is on its way, so the stream ends once
+    /// nothing has arrived for a second after the final commit instead.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    /// use elevenlabs_rs::utils::transcode::AudioEncoding;
+    /// use futures_util::stream;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let pcm = std::fs::read("speech_16k.pcm")?;
+    ///     let chunks = pcm
+    ///         .chunks(3200)
+    ///         .map(Bytes::copy_from_slice)
+    ///         .collect::<Vec<_>>();
+    ///     let endpoint = RealtimeSpeechToText::new(stream::iter(chunks))
+    ///         .with_audio_encoding(AudioEncoding::Pcm { sample_rate: 16000 })
+    ///         .with_language_code("en");
+    ///
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let mut transcripts = Box::pin(c.hit_stt_ws(endpoint).await?);
+    ///     while let Some(response) = transcripts.next().await {
+    ///         match response? {
+    ///             RealtimeTranscriptResponse::PartialTranscript(t) => println!("... {}", t.text()),
+    ///             RealtimeTranscriptResponse::CommittedTranscript(t) => println!("{}", t.text()),
+    ///             _ => {}
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub struct RealtimeSpeechToText<S>
+    where
+        S: Stream<Item = Bytes> + Send + 'static,
+    {
+        audio_stream: S,
+        model_id: String,
+        audio_encoding: AudioEncoding,
+        language_code: Option<String>,
+        commit_strategy: Option<CommitStrategy>,
+        include_timestamps: Option<bool>,
+        vad_silence_threshold_secs: Option<f32>,
+    }
+
+    impl<S> RealtimeSpeechToText<S>
+    where
+        S: Stream<Item = Bytes> + Send + 'static,
+    {
+        pub fn new(audio_stream: S) -> Self {
+            RealtimeSpeechToText {
+                audio_stream,
+                model_id: DEFAULT_REALTIME_MODEL_ID.to_string(),
+                audio_encoding: AudioEncoding::Pcm { sample_rate: 16000 },
+                language_code: None,
+                commit_strategy: None,
+                include_timestamps: None,
+                vad_silence_threshold_secs: None,
+            }
+        }
+        pub fn with_model_id(mut self, model_id: &str) -> Self {
+            self.model_id = model_id.to_string();
+            self
+        }
+        /// The encoding of the audio chunks, 16kHz PCM by default
+        pub fn with_audio_encoding(mut self, audio_encoding: AudioEncoding) -> Self {
+            self.audio_encoding = audio_encoding;
+            self
+        }
+        pub fn with_language_code(mut self, language_code: &str) -> Self {
+            self.language_code = Some(language_code.to_string());
+            self
+        }
+        pub fn with_commit_strategy(mut self, commit_strategy: CommitStrategy) -> Self {
+            self.commit_strategy = Some(commit_strategy);
+            self
+        }
+        /// Send word timestamps with each committed transcript
+        pub fn with_include_timestamps(mut self, include_timestamps: bool) -> Self {
+            self.include_timestamps = Some(include_timestamps);
+            self
+        }
+        /// With [CommitStrategy::Vad], how long a silence commits the transcript
+        pub fn with_vad_silence_threshold_secs(mut self, secs: f32) -> Self {
+            self.vad_silence_threshold_secs = Some(secs);
+            self
+        }
+        pub(crate) fn audio_encoding(&self) -> AudioEncoding {
+            self.audio_encoding
+        }
+        pub(crate) fn include_timestamps(&self) -> bool {
+            self.include_timestamps.unwrap_or(false)
+        }
+        pub(crate) fn commits_on_vad(&self) -> bool {
+            self.commit_strategy == Some(CommitStrategy::Vad)
+        }
+        pub(crate) fn audio_stream(self) -> S {
+            self.audio_stream
+        }
+        pub fn url(&self) -> Url {
+            let mut url = WS_BASE_URL.parse::<Url>().unwrap();
+            url.set_path(REALTIME_PATH);
+            {
+                let mut query = url.query_pairs_mut();
+                query.append_pair("model_id", &self.model_id);
+                query.append_pair("audio_format", &self.audio_encoding.to_string());
+                if let Some(language_code) = &self.language_code {
+                    query.append_pair("language_code", language_code);
+                }
+                if let Some(commit_strategy) = &self.commit_strategy {
+                    query.append_pair("commit_strategy", commit_strategy.as_str());
+                }
+                if let Some(include_timestamps) = self.include_timestamps {
+                    query.append_pair("include_timestamps", &include_timestamps.to_string());
+                }
+                if let Some(secs) = self.vad_silence_threshold_secs {
+                    query.append_pair("vad_silence_threshold_secs", &secs.to_string());
+                }
+            }
+            url
+        }
+    }
+
+    /// When the audio received so far is committed into a final transcript
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum CommitStrategy {
+        /// Only when the client commits, which happens here when the audio stream ends
+        Manual,
+        /// Whenever the speaker pauses
+        Vad,
+    }
+
+    impl CommitStrategy {
+        fn as_str(&self) -> &'static str {
+            match self {
+                CommitStrategy::Manual => "manual",
+                CommitStrategy::Vad => "vad",
+            }
+        }
+    }
+
+    /// A chunk of audio sent to the realtime endpoint
+    #[derive(Clone, Debug, Serialize)]
+    pub struct InputAudioChunk {
+        message_type: &'static str,
+        audio_base_64: String,
+        commit: bool,
+        sample_rate: u32,
+    }
+
+    impl InputAudioChunk {
+        pub fn new(audio: &[u8], commit: bool, sample_rate: u32) -> Self {
+            InputAudioChunk {
+                message_type: "input_audio_chunk",
+                audio_base_64: BASE64_STANDARD.encode(audio),
+                commit,
+                sample_rate,
+            }
+        }
+        pub fn json(self) -> Result<String> {
+            serde_json::to_string(&self).map_err(Into::into)
+        }
+    }
+
+    /// A message from the realtime endpoint
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[serde(tag = "message_type", rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum RealtimeTranscriptResponse {
+        SessionStarted(SessionStarted),
+        PartialTranscript(PartialTranscript),
+        CommittedTranscript(CommittedTranscript),
+        CommittedTranscriptWithTimestamps(CommittedTranscriptWithTimestamps),
+        /// A message type without a variant of its own
+        #[serde(other)]
+        Unknown,
+    }
+
+    impl RealtimeTranscriptResponse {
+        /// Parse a text frame, turning error messages into [ApiError]s
+        pub(crate) fn from_frame(text: &str) -> Result<Self> {
+            let value = serde_json::from_str::<Value>(text)?;
+            if let Some(error) = value.get("error") {
+                let status = value
+                    .get("message_type")
+                    .and_then(Value::as_str)
+                    .unwrap_or("error");
+                let message = error
+                    .as_str()
+                    .map_or_else(|| error.to_string(), str::to_string);
+                return Err(ApiError::new(status, &message).into());
+            }
+            Ok(serde_json::from_value(value)?)
+        }
+        pub(crate) fn is_committed(&self, with_timestamps: bool) -> bool {
+            match self {
+                RealtimeTranscriptResponse::CommittedTranscript(_) => !with_timestamps,
+                RealtimeTranscriptResponse::CommittedTranscriptWithTimestamps(_) => true,
+                _ => false,
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    pub struct SessionStarted {
+        session_id: String,
+        config: Option<Value>,
+    }
+
+    impl SessionStarted {
+        pub fn session_id(&self) -> &str {
+            &self.session_id
+        }
+        pub fn config(&self) -> Option<&Value> {
+            self.config.as_ref()
+        }
+    }
+
+    /// A transcript of the audio since the last commit, which may still change
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    pub struct PartialTranscript {
+        text: String,
+    }
+
+    impl PartialTranscript {
+        pub fn text(&self) -> &str {
+            &self.text
+        }
+    }
+
+    /// The final transcript of the audio up to a commit
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    pub struct CommittedTranscript {
+        text: String,
+    }
+
+    impl CommittedTranscript {
+        pub fn text(&self) -> &str {
+            &self.text
+        }
+    }
+
+    /// A committed transcript with word timestamps
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    pub struct CommittedTranscriptWithTimestamps {
+        text: String,
+        language_code: Option<String>,
+        #[serde(default)]
+        words: Vec<Word>,
+    }
+
+    impl CommittedTranscriptWithTimestamps {
+        pub fn text(&self) -> &str {
+            &self.text
+        }
+        pub fn language_code(&self) -> Option<&str> {
+            self.language_code.as_deref()
+        }
+        pub fn words(&self) -> &[Word] {
+            &self.words
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn error_frames_are_api_errors() {
+            let frame = r#"{"message_type":"auth_error","error":"invalid api key"}"#;
+            let error = RealtimeTranscriptResponse::from_frame(frame).unwrap_err();
+            let error = error.downcast_ref::<ApiError>().unwrap();
+            assert_eq!(error.status(), "auth_error");
+            assert_eq!(error.message(), "invalid api key");
+        }
+
+        #[test]
+        fn unknown_message_type() {
+            let frame = r#"{"message_type":"session_ended","reason":"idle"}"#;
+            assert_eq!(
+                RealtimeTranscriptResponse::from_frame(frame).unwrap(),
+                RealtimeTranscriptResponse::Unknown
+            );
+        }
+
+        #[test]
+        fn committed_transcripts() {
+            let committed = RealtimeTranscriptResponse::from_frame(
+                r#"{"message_type":"committed_transcript","text":"hi"}"#,
+            )
+            .unwrap();
+            let with_timestamps = RealtimeTranscriptResponse::from_frame(
+                r#"{"message_type":"committed_transcript_with_timestamps","text":"hi"}"#,
+            )
+            .unwrap();
+            let partial = RealtimeTranscriptResponse::from_frame(
+                r#"{"message_type":"partial_transcript","text":"hi"}"#,
+            )
+            .unwrap();
+            assert!(committed.is_committed(false));
+            // With timestamps, the timestamped transcript follows the plain one
+            assert!(!committed.is_committed(true));
+            assert!(with_timestamps.is_committed(false));
+            assert!(with_timestamps.is_committed(true));
+            assert!(!partial.is_committed(false));
+            assert!(!partial.is_committed(true));
+        }
+    }
+}
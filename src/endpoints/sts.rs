@@ -4,7 +4,7 @@ use super::*;
 pub use crate::endpoints::tts::SpeechQuery;
 pub use crate::endpoints::voice::VoiceSettings;
 use crate::error::Error;
-use futures_util::{Stream, StreamExt};
+use futures_util::Stream;
 use std::pin::Pin;
 
 const STS_PATH: &str = "/v1/speech-to-speech";
@@ -40,17 +40,33 @@ pub struct SpeechToSpeech {
 /// Speech-to-speech body
 #[derive(Debug, Clone)]
 pub struct SpeechToSpeechBody {
-    audio: String,
+    audio: AudioFile,
     model_id: Option<String>,
     voice_settings: Option<VoiceSettings>,
     seed: Option<u64>,
 }
 
+/// The audio to convert, either on disk or already in memory
+#[derive(Debug, Clone)]
+enum AudioFile {
+    Path(String),
+    Upload(FileUpload),
+}
+
 impl SpeechToSpeechBody {
     /// Create a new SpeechToSpeechBody
     pub fn new(audio: &str) -> Self {
         SpeechToSpeechBody {
-            audio: audio.to_string(),
+            audio: AudioFile::Path(audio.to_string()),
+            model_id: None,
+            voice_settings: None,
+            seed: None,
+        }
+    }
+    /// Create a new SpeechToSpeechBody from audio that is already in memory
+    pub fn from_upload(audio: FileUpload) -> Self {
+        SpeechToSpeechBody {
+            audio: AudioFile::Upload(audio),
             model_id: None,
             voice_settings: None,
             seed: None,
@@ -71,17 +87,26 @@ impl SpeechToSpeechBody {
         self.seed = Some(seed);
         self
     }
+    /// The seed, `None` for a random seed chosen by the API
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
     fn to_form(&self) -> Result<Form> {
         let mut form = Form::new();
-        let path = std::path::Path::new(&self.audio);
-        let audio_bytes = std::fs::read(path)?;
-        let file_name = path
-            .to_str()
-            .ok_or(Box::new(Error::PathNotValidUTF8))?
-            .to_string();
-        let audio = Part::bytes(audio_bytes)
-            .file_name(file_name)
-            .mime_str("audio/mpeg")?;
+        let audio = match &self.audio {
+            AudioFile::Path(path) => {
+                let path = std::path::Path::new(path);
+                let audio_bytes = std::fs::read(path)?;
+                let file_name = path
+                    .to_str()
+                    .ok_or(Box::new(Error::PathNotValidUTF8))?
+                    .to_string();
+                Part::bytes(audio_bytes)
+                    .file_name(file_name)
+                    .mime_str("audio/mpeg")?
+            }
+            AudioFile::Upload(upload) => upload.to_part()?,
+        };
         form = form.part("audio", audio);
         if let Some(model_id) = &self.model_id {
             form = form.text("model_id", model_id.clone());
@@ -142,6 +167,12 @@ impl Endpoint for SpeechToSpeech {
         url.set_query(self.any_query().as_deref());
         url
     }
+    fn apply_defaults(&mut self, defaults: &DefaultSettings) {
+        if self.speech_to_speech_body.voice_settings.is_none() {
+            self.speech_to_speech_body.voice_settings = defaults.voice_settings().cloned();
+        }
+        SpeechQuery::apply_defaults(&mut self.speech_query, defaults);
+    }
 }
 /// Speech-to-speech stream endpoint
 ///
@@ -206,9 +237,7 @@ impl Endpoint for SpeechToSpeechStream {
         ))
     }
     async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
-        let stream = resp.bytes_stream();
-        let stream = stream.map(|r| r.map_err(Into::into));
-        Ok(Box::pin(stream))
+        Ok(Box::pin(audio_stream(resp)))
     }
     fn url(&self) -> Url {
         let mut url = BASE_URL.parse::<Url>().unwrap();
@@ -216,4 +245,10 @@ impl Endpoint for SpeechToSpeechStream {
         url.set_query(self.any_query().as_deref());
         url
     }
+    fn apply_defaults(&mut self, defaults: &DefaultSettings) {
+        if self.speech_to_speech_body.voice_settings.is_none() {
+            self.speech_to_speech_body.voice_settings = defaults.voice_settings().cloned();
+        }
+        SpeechQuery::apply_defaults(&mut self.speech_query, defaults);
+    }
 }
@@ -0,0 +1,382 @@
+//! The text to dialogue endpoints
+//!
+//! Generate a multi-speaker dialogue with the Eleven v3 model. Each [DialogueInput] is one
+//! speaker's turn, and can carry [AudioTag]s such as `[whispers]` or `[laughs]` that
+//! direct the delivery of the text that follows them.
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/text-to-dialogue/convert) for more information.
+#![allow(dead_code)]
+use super::*;
+use crate::error::DialogueError;
+use crate::utils::cost::Billable;
+use futures_util::Stream;
+use std::pin::Pin;
+
+const TEXT_TO_DIALOGUE_PATH: &str = "/v1/text-to-dialogue";
+const OUTPUT_FORMAT_QUERY: &str = "output_format";
+
+/// Text to Dialogue endpoint
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::play;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let inputs = vec![
+///         DialogueInput::new(PreMadeVoiceID::Alice)
+///             .with_tag(AudioTag::Whispers)
+///             .with_text("Did you hear that?"),
+///         DialogueInput::new(PreMadeVoiceID::Brian)
+///             .with_text("Hear what?")
+///             .with_tag(AudioTag::Laughs),
+///     ];
+///     let body = TextToDialogueBody::new(inputs);
+///     let speech = c.hit(TextToDialogue::new(body)).await?;
+///     play(speech)?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TextToDialogue {
+    body: TextToDialogueBody,
+    output_format: Option<String>,
+}
+
+impl TextToDialogue {
+    pub fn new(body: TextToDialogueBody) -> Self {
+        TextToDialogue {
+            body,
+            output_format: None,
+        }
+    }
+    pub fn with_output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = Some(output_format_query(&output_format));
+        self
+    }
+}
+
+impl Endpoint for TextToDialogue {
+    type ResponseBody = Bytes;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        self.body.validate()?;
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.bytes().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(TEXT_TO_DIALOGUE_PATH);
+        url.set_query(self.output_format.as_deref());
+        url
+    }
+    fn apply_defaults(&mut self, defaults: &DefaultSettings) {
+        if self.output_format.is_none() {
+            self.output_format = defaults.output_format().map(output_format_query);
+        }
+    }
+}
+
+/// Text to Dialogue Stream endpoint
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::stream_audio;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let inputs = vec![
+///         DialogueInput::new(PreMadeVoiceID::Alice)
+///             .with_tag(AudioTag::Excited)
+///             .with_text("We made it!"),
+///         DialogueInput::new(PreMadeVoiceID::Brian).with_text("Barely."),
+///     ];
+///     let body = TextToDialogueBody::new(inputs);
+///     let mut stream = c.hit(TextToDialogueStream::new(body)).await?;
+///     stream_audio(&mut stream).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TextToDialogueStream {
+    body: TextToDialogueBody,
+    output_format: Option<String>,
+}
+
+impl TextToDialogueStream {
+    pub fn new(body: TextToDialogueBody) -> Self {
+        TextToDialogueStream {
+            body,
+            output_format: None,
+        }
+    }
+    pub fn with_output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = Some(output_format_query(&output_format));
+        self
+    }
+}
+
+type TextToDialogueStreamResponse = Pin<Box<dyn Stream<Item = Result<Bytes>>>>;
+
+impl Endpoint for TextToDialogueStream {
+    type ResponseBody = TextToDialogueStreamResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        self.body.validate()?;
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(Box::pin(audio_stream(resp)))
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(&format!("{}{}", TEXT_TO_DIALOGUE_PATH, STREAM_PATH));
+        url.set_query(self.output_format.as_deref());
+        url
+    }
+    fn apply_defaults(&mut self, defaults: &DefaultSettings) {
+        if self.output_format.is_none() {
+            self.output_format = defaults.output_format().map(output_format_query);
+        }
+    }
+}
+
+fn output_format_query(output_format: &OutputFormat) -> String {
+    query_pair(OUTPUT_FORMAT_QUERY, output_format.as_str())
+}
+
+/// Text to Dialogue body for all text to dialogue endpoints
+#[derive(Clone, Debug, Serialize)]
+pub struct TextToDialogueBody {
+    inputs: Vec<DialogueInput>,
+    model_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    settings: Option<DialogueSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+}
+
+impl TextToDialogueBody {
+    /// A dialogue using [Model::ElevenV3]
+    pub fn new(inputs: Vec<DialogueInput>) -> Self {
+        TextToDialogueBody {
+            inputs,
+            model_id: Model::ElevenV3.to_string(),
+            settings: None,
+            seed: None,
+        }
+    }
+    pub fn with_model_id<T: Into<String>>(mut self, model_id: T) -> Self {
+        self.model_id = model_id.into();
+        self
+    }
+    /// `stability` expected to be greater or equal to 0.0 and less or equal to 1.0
+    pub fn with_stability(mut self, stability: f32) -> Self {
+        self.settings = Some(DialogueSettings { stability });
+        self
+    }
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+    pub fn inputs(&self) -> &[DialogueInput] {
+        &self.inputs
+    }
+    pub fn stability(&self) -> Option<f32> {
+        self.settings.as_ref().map(|settings| settings.stability)
+    }
+    /// The seed, `None` for a random seed chosen by the API
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+    /// The number of characters billed, audio tags included
+    pub fn estimated_characters(&self) -> u64 {
+        self.inputs
+            .iter()
+            .map(|input| input.text.chars().count() as u64)
+            .sum()
+    }
+    /// Check the inputs before sending them.
+    ///
+    /// The endpoints call this when building the request, so an invalid dialogue fails
+    /// without a round trip.
+    pub fn validate(&self) -> std::result::Result<(), DialogueError> {
+        if self.inputs.is_empty() {
+            return Err(DialogueError::NoInputs);
+        }
+        for (index, input) in self.inputs.iter().enumerate() {
+            input.validate(index)?;
+        }
+        Ok(())
+    }
+}
+
+impl Billable for TextToDialogue {
+    fn estimated_characters(&self) -> u64 {
+        self.body.estimated_characters()
+    }
+    fn model_id(&self) -> Option<&str> {
+        Some(&self.body.model_id)
+    }
+}
+
+impl Billable for TextToDialogueStream {
+    fn estimated_characters(&self) -> u64 {
+        self.body.estimated_characters()
+    }
+    fn model_id(&self) -> Option<&str> {
+        Some(&self.body.model_id)
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct DialogueSettings {
+    stability: f32,
+}
+
+/// One speaker's turn in a dialogue
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct DialogueInput {
+    text: String,
+    voice_id: String,
+}
+
+impl DialogueInput {
+    pub fn new<T: Into<String>>(voice_id: T) -> Self {
+        DialogueInput {
+            text: String::new(),
+            voice_id: voice_id.into(),
+        }
+    }
+    /// Append text to this turn
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.push(text);
+        self
+    }
+    /// Append an audio tag, which directs the delivery of the text after it
+    pub fn with_tag(mut self, tag: AudioTag) -> Self {
+        self.push(&tag.to_string());
+        self
+    }
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    pub fn voice_id(&self) -> &str {
+        &self.voice_id
+    }
+    fn push(&mut self, segment: &str) {
+        let segment = segment.trim();
+        if segment.is_empty() {
+            return;
+        }
+        if !self.text.is_empty() {
+            self.text.push(' ');
+        }
+        self.text.push_str(segment);
+    }
+    fn validate(&self, index: usize) -> std::result::Result<(), DialogueError> {
+        if self.voice_id.trim().is_empty() {
+            return Err(DialogueError::EmptyVoiceID(index));
+        }
+        let mut open = None;
+        let mut has_text = false;
+        for (position, c) in self.text.char_indices() {
+            match (c, open) {
+                ('[', None) => open = Some(position),
+                (']', Some(start)) => {
+                    if self.text[start + 1..position].trim().is_empty() {
+                        return Err(DialogueError::EmptyTag(index));
+                    }
+                    open = None;
+                }
+                ('[', Some(_)) | (']', None) => {
+                    return Err(DialogueError::UnbalancedTag { index, position })
+                }
+                (c, None) if !c.is_whitespace() => has_text = true,
+                _ => {}
+            }
+        }
+        if let Some(position) = open {
+            return Err(DialogueError::UnbalancedTag { index, position });
+        }
+        if !has_text {
+            return Err(DialogueError::EmptyText(index));
+        }
+        Ok(())
+    }
+}
+
+/// Eleven v3 audio tags
+///
+/// # Example
+/// ```
+/// use elevenlabs_rs::AudioTag;
+///
+/// assert_eq!(AudioTag::Whispers.to_string(), "[whispers]");
+/// assert_eq!(AudioTag::Custom("strong French accent".into()).to_string(), "[strong French accent]");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AudioTag {
+    Laughs,
+    LaughsHarder,
+    StartsLaughing,
+    Wheezing,
+    Whispers,
+    Sighs,
+    Exhales,
+    Sarcastic,
+    Curious,
+    Excited,
+    Crying,
+    Snorts,
+    Mischievously,
+    Shouting,
+    Interrupting,
+    Overlapping,
+    Pause,
+    /// Any other tag, written without the brackets
+    Custom(String),
+}
+
+impl AudioTag {
+    pub fn as_str(&self) -> &str {
+        match self {
+            AudioTag::Laughs => "laughs",
+            AudioTag::LaughsHarder => "laughs harder",
+            AudioTag::StartsLaughing => "starts laughing",
+            AudioTag::Wheezing => "wheezing",
+            AudioTag::Whispers => "whispers",
+            AudioTag::Sighs => "sighs",
+            AudioTag::Exhales => "exhales",
+            AudioTag::Sarcastic => "sarcastic",
+            AudioTag::Curious => "curious",
+            AudioTag::Excited => "excited",
+            AudioTag::Crying => "crying",
+            AudioTag::Snorts => "snorts",
+            AudioTag::Mischievously => "mischievously",
+            AudioTag::Shouting => "shouting",
+            AudioTag::Interrupting => "interrupting",
+            AudioTag::Overlapping => "overlapping",
+            AudioTag::Pause => "pause",
+            AudioTag::Custom(tag) => tag,
+        }
+    }
+}
+
+impl std::fmt::Display for AudioTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}]", self.as_str())
+    }
+}
@@ -2,9 +2,11 @@
 //! The text-to-speech endpoints
 use super::*;
 use crate::endpoints::voice::VoiceSettings;
+use crate::error::ApiError;
+use crate::utils::cost::Billable;
 use async_stream::try_stream;
 use base64::{engine::general_purpose, Engine as _};
-use futures_util::{Stream, StreamExt};
+use futures_util::Stream;
 use std::pin::Pin;
 
 const TTS_PATH: &str = "/v1/text-to-speech";
@@ -63,6 +65,10 @@ impl Endpoint for TextToSpeech {
         url.set_query(self.any_query().as_deref());
         url
     }
+    fn apply_defaults(&mut self, defaults: &DefaultSettings) {
+        self.text_to_speech_body.apply_defaults(defaults);
+        SpeechQuery::apply_defaults(&mut self.speech_query, defaults);
+    }
 }
 
 impl TextToSpeech {
@@ -91,6 +97,7 @@ impl TextToSpeech {
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct TextToSpeechBody {
     text: String,
+    #[serde(skip_serializing_if = "ModelID::is_empty")]
     model_id: ModelID,
     #[serde(skip_serializing_if = "Option::is_none")]
     voice_settings: Option<VoiceSettings>,
@@ -116,6 +123,14 @@ impl TextToSpeechBody {
             ..Default::default()
         }
     }
+    /// A body without a model, which takes the client's default model if one is set,
+    /// otherwise the API's default
+    pub fn from_text(text: &str) -> Self {
+        TextToSpeechBody {
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
     pub fn with_pronunciation_dict(mut self, pronunciation_id: &str, version_id: &str) -> Self {
         if let Some(dictionary) = &mut self.pronunciation_dictionary_locators {
             dictionary.push(PronunciationDictionaryLocator {
@@ -154,7 +169,64 @@ impl TextToSpeechBody {
         self.next_text_ids = Some(next_text_ids);
         self
     }
+    /// The number of characters billed for the text, context such as `previous_text`
+    /// is not billed
+    pub fn estimated_characters(&self) -> u64 {
+        self.text.chars().count() as u64
+    }
+    /// The model ID, `None` if the body was created without one
+    pub fn model_id(&self) -> Option<&str> {
+        (!self.model_id.is_empty()).then_some(self.model_id.0.as_str())
+    }
+    /// The seed, `None` for a random seed chosen by the API
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+    fn apply_defaults(&mut self, defaults: &DefaultSettings) {
+        if let (true, Some(model)) = (self.model_id.is_empty(), defaults.model()) {
+            self.model_id = ModelID::from(model.to_string());
+        }
+        if self.voice_settings.is_none() {
+            self.voice_settings = defaults.voice_settings().cloned();
+        }
+    }
+}
+impl Billable for TextToSpeech {
+    fn estimated_characters(&self) -> u64 {
+        self.text_to_speech_body.estimated_characters()
+    }
+    fn model_id(&self) -> Option<&str> {
+        self.text_to_speech_body.model_id()
+    }
+}
+
+impl Billable for TextToSpeechStream {
+    fn estimated_characters(&self) -> u64 {
+        self.text_to_speech_body.estimated_characters()
+    }
+    fn model_id(&self) -> Option<&str> {
+        self.text_to_speech_body.model_id()
+    }
+}
+
+impl Billable for TextToSpeechWithTimestamps {
+    fn estimated_characters(&self) -> u64 {
+        self.text_to_speech_body.estimated_characters()
+    }
+    fn model_id(&self) -> Option<&str> {
+        self.text_to_speech_body.model_id()
+    }
+}
+
+impl Billable for TextToSpeechStreamWithTimestamps {
+    fn estimated_characters(&self) -> u64 {
+        self.text_to_speech_body.estimated_characters()
+    }
+    fn model_id(&self) -> Option<&str> {
+        self.text_to_speech_body.model_id()
+    }
 }
+
 #[derive(Clone, Debug, Serialize)]
 struct PronunciationDictionaryLocator {
     pronunciation_dictionary_id: String,
@@ -171,29 +243,32 @@ pub struct SpeechQuery {
 
 impl SpeechQuery {
     pub fn with_latency(mut self, latency: Latency) -> Self {
-        self.latency = Some(format!("{}={}", LATENCY_QUERY, latency as u8));
+        self.latency = Some(query_pair(LATENCY_QUERY, latency as u8));
         self
     }
     pub fn with_output_format(mut self, output_format: OutputFormat) -> Self {
-        self.output_format = Some(format!(
-            "{}={}",
-            OUTPUT_FORMAT_QUERY,
-            output_format.to_query()
-        ));
+        self.output_format = Some(query_pair(OUTPUT_FORMAT_QUERY, output_format.as_str()));
         self
     }
     pub fn with_logging(mut self, enable_logging: bool) -> Self {
-        self.enable_logging = Some(format!("{}={}", ENABLE_LOGGING_QUERY, enable_logging));
+        self.enable_logging = Some(query_pair(ENABLE_LOGGING_QUERY, enable_logging));
         self
     }
     pub fn with_ssml_parsing(mut self, enable_ssml_parsing: bool) -> Self {
-        self.enable_ssml_parsing = Some(format!(
-            "{}={}",
-            ENABLE_SSML_PARSING_QUERY, enable_ssml_parsing
-        ));
+        self.enable_ssml_parsing = Some(query_pair(ENABLE_SSML_PARSING_QUERY, enable_ssml_parsing));
         self
     }
 
+    /// Set the default output format on `query` unless it already has one
+    pub(crate) fn apply_defaults(query: &mut Option<SpeechQuery>, defaults: &DefaultSettings) {
+        if let Some(output_format) = defaults.output_format() {
+            let query = query.get_or_insert_with(SpeechQuery::default);
+            if query.output_format.is_none() {
+                *query = std::mem::take(query).with_output_format(output_format.clone());
+            }
+        }
+    }
+
     pub fn to_string(&self) -> String {
         let mut query = String::new();
 
@@ -284,15 +359,18 @@ impl Endpoint for TextToSpeechStream {
     }
 
     async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
-        let stream = resp.bytes_stream();
-        let stream = stream.map(|r| r.map_err(Into::into));
-        Ok(Box::pin(stream))
+        Ok(Box::pin(audio_stream(resp)))
     }
     fn url(&self) -> Url {
         let mut url = BASE_URL.parse::<Url>().unwrap();
         url.set_path(&format!("{}/{}{}", TTS_PATH, self.voice_id.0, STREAM_PATH));
+        url.set_query(self.any_query().as_deref());
         url
     }
+    fn apply_defaults(&mut self, defaults: &DefaultSettings) {
+        self.text_to_speech_body.apply_defaults(defaults);
+        SpeechQuery::apply_defaults(&mut self.speech_query, defaults);
+    }
 }
 /// Text to Speech with Timestamps endpoint
 ///
@@ -373,12 +451,17 @@ impl Endpoint for TextToSpeechWithTimestamps {
         url.set_query(self.any_query().as_deref());
         url
     }
+    fn apply_defaults(&mut self, defaults: &DefaultSettings) {
+        self.text_to_speech_body.apply_defaults(defaults);
+        SpeechQuery::apply_defaults(&mut self.speech_query, defaults);
+    }
 }
 
 /// The response from the TextToSpeechWithTimestamps endpoint
 ///
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct TextToSpeechWithTimestampsResponse {
     alignment: Option<Alignment>,
     audio_base64: String,
@@ -421,7 +504,8 @@ impl TextToSpeechWithTimestampsResponse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Alignment {
     character_end_times_seconds: Vec<f32>,
     character_start_times_seconds: Vec<f32>,
@@ -531,6 +615,9 @@ impl Endpoint for TextToSpeechStreamWithTimestamps {
                 let bytes = bytes_result?;
                 if bytes.ends_with(end_of_chunk) {
                     buf.push_str(std::str::from_utf8(&bytes)?);
+                    if let Some(error) = ApiError::from_frame(buf.as_bytes()) {
+                        Err(error)?;
+                    }
                     let json: TextToSpeechWithTimestampsResponse = serde_json::from_str(&buf)?;
                     yield json;
                     buf.clear();
@@ -538,6 +625,10 @@ impl Endpoint for TextToSpeechStreamWithTimestamps {
                     buf.push_str(std::str::from_utf8(&bytes)?);
                 }
             }
+            // An error frame can arrive without the chunk terminator before the stream ends
+            if let Some(error) = ApiError::from_frame(buf.as_bytes()) {
+                Err(error)?;
+            }
         }))
     }
     fn url(&self) -> Url {
@@ -549,6 +640,10 @@ impl Endpoint for TextToSpeechStreamWithTimestamps {
         url.set_query(self.any_query().as_deref());
         url
     }
+    fn apply_defaults(&mut self, defaults: &DefaultSettings) {
+        self.text_to_speech_body.apply_defaults(defaults);
+        SpeechQuery::apply_defaults(&mut self.speech_query, defaults);
+    }
 }
 
 pub mod ws {
@@ -687,22 +782,11 @@ pub mod ws {
                 "{}/{}{}",
                 TTS_PATH, self.path_params.voice_id.0, WS_STREAM_PATH
             ));
-            let mut query = String::new();
             if let Some(q) = &self.speech_query {
-                query.push_str(&q.to_string());
-                query.push('&');
-                query.push_str(&format!(
-                    "{}={}",
-                    MODEL_ID_QUERY, self.path_params.model_id.0
-                ));
-                url.set_query(Some(&query));
-            } else {
-                query.push_str(&format!(
-                    "?{}={}",
-                    MODEL_ID_QUERY, self.path_params.model_id.0
-                ));
-                url.set_query(Some(&query));
+                url.set_query(Some(&q.to_string()));
             }
+            url.query_pairs_mut()
+                .append_pair(MODEL_ID_QUERY, &self.path_params.model_id.0);
             url.to_string()
         }
         pub fn bos_message(&self) -> &BOSMessage {
@@ -873,7 +957,8 @@ pub mod ws {
         }
     }
 
-    #[derive(Clone, Debug, Default, Deserialize)]
+    #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+    #[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
     #[serde(rename_all = "camelCase")]
     pub struct WebSocketTTSResponse {
         audio: Option<String>,
@@ -908,7 +993,8 @@ pub mod ws {
         }
     }
 
-    #[derive(Clone, Debug, Deserialize)]
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
     #[serde(rename_all = "camelCase")]
     pub struct WebSocketAlignment {
         char_start_times_ms: Vec<f32>,
@@ -916,3 +1002,36 @@ pub mod ws {
         chars: Vec<String>,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ws::*;
+    use super::*;
+
+    fn ws_tts() -> WebSocketTTS<futures_util::stream::Empty<String>> {
+        let body = WebSocketTTSBody::new(BOSMessage::default(), futures_util::stream::empty());
+        WebSocketTTS::new("voice", "eleven_flash_v2_5", body)
+    }
+
+    #[test]
+    fn ws_url_without_query() {
+        assert_eq!(
+            ws_tts().url(),
+            "wss://api.elevenlabs.io/v1/text-to-speech/voice/stream-input?model_id=eleven_flash_v2_5"
+        );
+    }
+
+    #[test]
+    fn ws_url_with_query() {
+        let query = SpeechQuery::default()
+            .with_latency(Latency::Max)
+            .with_output_format(OutputFormat::Pcm24000Hz)
+            .with_ssml_parsing(true);
+        assert_eq!(
+            ws_tts().with_query(query).url(),
+            "wss://api.elevenlabs.io/v1/text-to-speech/voice/stream-input\
+             ?optimize_streaming_latency=3&output_format=pcm_24000\
+             &enable_ssml_parsing=true&model_id=eleven_flash_v2_5"
+        );
+    }
+}
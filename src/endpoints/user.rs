@@ -37,7 +37,8 @@ impl Endpoint for GetUserSubscriptionInfo {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Subscription {
     tier: String,
     character_count: i64,
@@ -60,12 +61,86 @@ pub struct Subscription {
     has_open_invoices: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl Subscription {
+    pub fn tier(&self) -> &str {
+        &self.tier
+    }
+    pub fn character_count(&self) -> i64 {
+        self.character_count
+    }
+    pub fn character_limit(&self) -> i64 {
+        self.character_limit
+    }
+    /// Characters left in the current period, `character_limit - character_count`
+    pub fn remaining_characters(&self) -> i64 {
+        self.character_limit - self.character_count
+    }
+    pub fn can_extend_character_limit(&self) -> bool {
+        self.can_extend_character_limit
+    }
+    pub fn allowed_to_extend_character_limit(&self) -> bool {
+        self.allowed_to_extend_character_limit
+    }
+    pub fn next_character_count_reset_unix(&self) -> i64 {
+        self.next_character_count_reset_unix
+    }
+    pub fn voice_limit(&self) -> i64 {
+        self.voice_limit
+    }
+    pub fn max_voice_add_edits(&self) -> i64 {
+        self.max_voice_add_edits
+    }
+    pub fn voice_add_edit_counter(&self) -> i64 {
+        self.voice_add_edit_counter
+    }
+    pub fn professional_voice_limit(&self) -> i64 {
+        self.professional_voice_limit
+    }
+    pub fn can_extend_voice_limit(&self) -> bool {
+        self.can_extend_voice_limit
+    }
+    pub fn can_use_instant_voice_cloning(&self) -> bool {
+        self.can_use_instant_voice_cloning
+    }
+    pub fn can_use_professional_voice_cloning(&self) -> bool {
+        self.can_use_professional_voice_cloning
+    }
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+    pub fn billing_period(&self) -> &str {
+        &self.billing_period
+    }
+    pub fn character_refresh_period(&self) -> &str {
+        &self.character_refresh_period
+    }
+    pub fn next_invoice(&self) -> Option<&NextInvoice> {
+        self.next_invoice.as_ref()
+    }
+    pub fn has_open_invoices(&self) -> Option<bool> {
+        self.has_open_invoices
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct NextInvoice {
     amount_due_cents: i64,
     next_payment_attempt_unix: i64,
 }
 
+impl NextInvoice {
+    pub fn amount_due_cents(&self) -> i64 {
+        self.amount_due_cents
+    }
+    pub fn next_payment_attempt_unix(&self) -> i64 {
+        self.next_payment_attempt_unix
+    }
+}
+
 /// Gets information about the user
 /// # Examples
 /// ```no_run
@@ -97,7 +172,8 @@ impl Endpoint for GetUserInfo {
         url
     }
 }
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct UserInfo {
     subscription: Subscription,
     is_new_user: bool,
@@ -4,15 +4,27 @@
 //! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/get-voices) for more information.
 //!
 use super::*;
+use crate::client::ElevenLabsClient;
 use crate::error::Error;
+use async_stream::try_stream;
+use futures_util::Stream;
 use std::collections::HashMap;
 use std::path::Path;
 
+const VOICES_V2_PATH: &str = "/v2/voices";
 const EDIT_VOICE_PATH: &str = "/edit";
 const EDIT_VOICE_SETTINGS_PATH: &str = "/settings/edit";
 const DEFAULT_SETTINGS_PATH: &str = "/v1/voices/settings/default";
 const VOICE_SETTINGS_PATH: &str = "/settings";
 const WITH_SETTINGS_QUERY: &str = "with_settings=true";
+const NEXT_PAGE_TOKEN_QUERY: &str = "next_page_token";
+const PAGE_SIZE_QUERY: &str = "page_size";
+const SEARCH_QUERY: &str = "search";
+const SORT_QUERY: &str = "sort";
+const SORT_DIRECTION_QUERY: &str = "sort_direction";
+const VOICE_TYPE_QUERY: &str = "voice_type";
+const CATEGORY_QUERY: &str = "category";
+const INCLUDE_TOTAL_COUNT_QUERY: &str = "include_total_count";
 
 /// Get all voices endpoint
 ///
@@ -79,6 +91,259 @@ impl Endpoint for GetVoiceIDByName {
     }
 }
 
+/// Get voices endpoint of the v2 API, with search, sorting and pagination
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let query = VoicesQuery::default()
+///         .with_search("narrator")
+///         .with_category(VoiceCategory::Professional)
+///         .with_sort(VoiceSort::CreatedAtUnix, SortDirection::Desc)
+///         .with_page_size(10);
+///     let resp = c.hit(GetVoicesV2::new(query)).await?;
+///     for voice in resp.voices() {
+///         println!("{} {}", voice.get_voice_id(), voice.get_name());
+///     }
+///     Ok(())
+/// }
+/// ```
+/// See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/voices/search) for more information.
+#[derive(Clone, Debug, Default)]
+pub struct GetVoicesV2(VoicesQuery);
+
+impl GetVoicesV2 {
+    pub fn new(query: VoicesQuery) -> Self {
+        GetVoicesV2(query)
+    }
+}
+
+impl Endpoint for GetVoicesV2 {
+    type ResponseBody = VoicesV2Response;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(VOICES_V2_PATH);
+        url.set_query(self.0.join().as_deref());
+        url
+    }
+}
+
+/// Page through the voices matching `query` with [GetVoicesV2], yielding each voice in turn.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let query = VoicesQuery::default().with_voice_type(VoiceType::Personal);
+///     let voices = paginate_voices(&c, query);
+///     pin_mut!(voices);
+///     while let Some(voice) = voices.next().await {
+///         println!("{}", voice?.get_name());
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn paginate_voices(
+    client: &ElevenLabsClient,
+    query: VoicesQuery,
+) -> impl Stream<Item = Result<VoiceResponseBody>> + '_ {
+    try_stream! {
+        let mut query = query;
+        loop {
+            let resp = client.hit(GetVoicesV2::new(query.clone())).await?;
+            for voice in resp.voices {
+                yield voice;
+            }
+            match resp.next_page_token {
+                Some(token) if resp.has_more => query = query.with_next_page_token(&token),
+                _ => break,
+            }
+        }
+    }
+}
+
+/// Voices query for [GetVoicesV2]
+#[derive(Clone, Debug, Default)]
+pub struct VoicesQuery {
+    pub next_page_token: Option<String>,
+    pub page_size: Option<String>,
+    pub search: Option<String>,
+    pub sort: Option<String>,
+    pub sort_direction: Option<String>,
+    pub voice_type: Option<String>,
+    pub category: Option<String>,
+    pub include_total_count: Option<String>,
+}
+
+impl VoicesQuery {
+    pub fn with_next_page_token(mut self, next_page_token: &str) -> Self {
+        self.next_page_token = Some(query_pair(NEXT_PAGE_TOKEN_QUERY, next_page_token));
+        self
+    }
+    /// `page_size` expected to be between 1 and 100, the API defaults to 10
+    pub fn with_page_size(mut self, page_size: u16) -> Self {
+        self.page_size = Some(query_pair(PAGE_SIZE_QUERY, page_size));
+        self
+    }
+    /// Search the name, description, labels and category of the voices
+    pub fn with_search(mut self, search: &str) -> Self {
+        self.search = Some(query_pair(SEARCH_QUERY, search));
+        self
+    }
+    pub fn with_sort(mut self, sort: VoiceSort, direction: SortDirection) -> Self {
+        self.sort = Some(query_pair(SORT_QUERY, sort.as_str()));
+        self.sort_direction = Some(query_pair(SORT_DIRECTION_QUERY, direction.as_str()));
+        self
+    }
+    pub fn with_voice_type(mut self, voice_type: VoiceType) -> Self {
+        self.voice_type = Some(query_pair(VOICE_TYPE_QUERY, voice_type.as_str()));
+        self
+    }
+    pub fn with_category(mut self, category: VoiceCategory) -> Self {
+        self.category = Some(query_pair(CATEGORY_QUERY, category.as_str()));
+        self
+    }
+    /// Whether the response includes [total_count](VoicesV2Response::total_count), which
+    /// makes the request slower
+    pub fn with_include_total_count(mut self, include_total_count: bool) -> Self {
+        self.include_total_count = Some(query_pair(INCLUDE_TOTAL_COUNT_QUERY, include_total_count));
+        self
+    }
+
+    fn join(&self) -> Option<String> {
+        let params = [
+            &self.next_page_token,
+            &self.page_size,
+            &self.search,
+            &self.sort,
+            &self.sort_direction,
+            &self.voice_type,
+            &self.category,
+            &self.include_total_count,
+        ]
+        .into_iter()
+        .flatten()
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+        (!params.is_empty()).then(|| params.join("&"))
+    }
+}
+
+/// What to sort [GetVoicesV2] results by
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VoiceSort {
+    CreatedAtUnix,
+    Name,
+}
+
+impl VoiceSort {
+    pub fn as_str(&self) -> &str {
+        match self {
+            VoiceSort::CreatedAtUnix => "created_at_unix",
+            VoiceSort::Name => "name",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SortDirection::Asc => "asc",
+            SortDirection::Desc => "desc",
+        }
+    }
+}
+
+/// Which voices [GetVoicesV2] returns
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VoiceType {
+    Personal,
+    Community,
+    Default,
+    Workspace,
+    /// All voices except the default ones
+    NonDefault,
+}
+
+impl VoiceType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            VoiceType::Personal => "personal",
+            VoiceType::Community => "community",
+            VoiceType::Default => "default",
+            VoiceType::Workspace => "workspace",
+            VoiceType::NonDefault => "non-default",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VoiceCategory {
+    Premade,
+    Cloned,
+    Generated,
+    Professional,
+}
+
+impl VoiceCategory {
+    pub fn as_str(&self) -> &str {
+        match self {
+            VoiceCategory::Premade => "premade",
+            VoiceCategory::Cloned => "cloned",
+            VoiceCategory::Generated => "generated",
+            VoiceCategory::Professional => "professional",
+        }
+    }
+}
+
+/// Get voices response body of the v2 API
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct VoicesV2Response {
+    voices: Vec<VoiceResponseBody>,
+    has_more: bool,
+    total_count: Option<u64>,
+    next_page_token: Option<String>,
+}
+
+impl VoicesV2Response {
+    pub fn voices(&self) -> &[VoiceResponseBody] {
+        &self.voices
+    }
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+    /// Only set when the query asked for it with
+    /// [with_include_total_count](VoicesQuery::with_include_total_count)
+    pub fn total_count(&self) -> Option<u64> {
+        self.total_count
+    }
+    /// The token to pass to [VoicesQuery::with_next_page_token] for the next page
+    pub fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+}
+
 /// Get the default voice settings endpoint
 /// # Example
 /// ```no_run
@@ -176,7 +441,6 @@ impl GetVoice {
     }
 }
 
-
 impl Endpoint for GetVoice {
     type ResponseBody = VoiceResponseBody;
 
@@ -260,8 +524,14 @@ impl Endpoint for DeleteVoice {
 ///   let endpoint = EditVoiceSettings::new("some_voice_id", body);
 ///   let resp = c.hit(endpoint).await?;
 ///   println!("{:#?}", resp);
+///
+///   // Round-trip the current settings
+///   let settings = c.hit(GetVoiceSettings::new("some_voice_id")).await?;
+///   let endpoint = EditVoiceSettings::from_settings("some_voice_id", settings.with_style(0.2));
+///   let resp = c.hit(endpoint).await?;
 ///   Ok(())
 /// }
+/// ```
 #[derive(Clone, Debug)]
 pub struct EditVoiceSettings {
     voice_id: VoiceID,
@@ -275,6 +545,10 @@ impl EditVoiceSettings {
             body,
         }
     }
+    /// Edit the settings to those fetched with [GetVoiceSettings] or [GetDefaultSettings]
+    pub fn from_settings<T: Into<String>>(voice_id: T, settings: VoiceSettings) -> Self {
+        Self::new(voice_id, settings.into())
+    }
 }
 
 impl Endpoint for EditVoiceSettings {
@@ -308,6 +582,8 @@ pub struct EditVoiceSettingsBody {
     style: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     use_speaker_boost: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    speed: Option<f32>,
 }
 
 impl EditVoiceSettingsBody {
@@ -317,6 +593,7 @@ impl EditVoiceSettingsBody {
             stability,
             style: None,
             use_speaker_boost: None,
+            speed: None,
         }
     }
     pub fn with_style(mut self, style: f32) -> Self {
@@ -327,6 +604,22 @@ impl EditVoiceSettingsBody {
         self.use_speaker_boost = Some(use_speaker_boost);
         self
     }
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = Some(speed);
+        self
+    }
+}
+
+impl From<VoiceSettings> for EditVoiceSettingsBody {
+    fn from(settings: VoiceSettings) -> Self {
+        EditVoiceSettingsBody {
+            similarity_boost: settings.similarity_boost,
+            stability: settings.stability,
+            style: settings.style,
+            use_speaker_boost: settings.use_speaker_boost,
+            speed: settings.speed,
+        }
+    }
 }
 
 /// Add a voice endpoint
@@ -368,8 +661,10 @@ impl Endpoint for AddVoice {
         Ok(RequestBody::Multipart(to_multipart(
             self.0.name.clone(),
             Some(self.0.files.clone()),
+            &self.0.samples,
             self.0.description.clone(),
             self.0.labels.clone(),
+            self.0.remove_background_noise,
         )?))
     }
 
@@ -384,12 +679,37 @@ impl Endpoint for AddVoice {
 }
 
 /// Add voice body
+///
+/// Samples can be file paths, in-memory [FileUpload]s, or both.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let reader = tokio::fs::File::open("upload.wav").await?;
+///     let samples = vec![
+///         FileUpload::new(Bytes::from(vec![0u8; 1024]), "first.mp3", "audio/mpeg"),
+///         FileUpload::from_async_read(reader, "second.wav", "audio/wav"),
+///     ];
+///     let body = AddVoiceBody::from_samples("John Doe", samples)
+///         .with_description("A public intellectual")
+///         .with_remove_background_noise(true);
+///     let resp = c.hit(AddVoice::new(body)).await?;
+///     println!("{:#?}", resp);
+///     Ok(())
+/// }
+/// ```
 #[derive(Clone, Debug)]
 pub struct AddVoiceBody {
     name: String,
     files: Vec<String>,
+    samples: Vec<FileUpload>,
     description: Option<String>,
     labels: Option<Vec<(String, String)>>,
+    remove_background_noise: Option<bool>,
 }
 
 impl AddVoiceBody {
@@ -397,10 +717,19 @@ impl AddVoiceBody {
         Self {
             name: name.to_string(),
             files,
+            samples: Vec::new(),
             description: None,
             labels: None,
+            remove_background_noise: None,
         }
     }
+    pub fn from_samples(name: &str, samples: Vec<FileUpload>) -> Self {
+        Self::new(name, Vec::new()).with_samples(samples)
+    }
+    pub fn with_samples(mut self, samples: Vec<FileUpload>) -> Self {
+        self.samples.extend(samples);
+        self
+    }
     pub fn with_description(mut self, description: &str) -> Self {
         self.description = Some(description.to_string());
         self
@@ -409,10 +738,17 @@ impl AddVoiceBody {
         self.labels = Some(labels);
         self
     }
+    /// Remove background noise from the samples before cloning.
+    /// Samples without background noise can get worse with this enabled.
+    pub fn with_remove_background_noise(mut self, remove_background_noise: bool) -> Self {
+        self.remove_background_noise = Some(remove_background_noise);
+        self
+    }
 }
 
 /// Add voice response
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct AddVoiceResponse {
     voice_id: String,
 }
@@ -449,8 +785,10 @@ impl Endpoint for EditVoice {
         Ok(RequestBody::Multipart(to_multipart(
             self.body.name.clone(),
             self.body.files.clone(),
+            &self.body.samples,
             self.body.description.clone(),
             self.body.labels.clone(),
+            self.body.remove_background_noise,
         )?))
     }
 
@@ -472,8 +810,10 @@ impl Endpoint for EditVoice {
 pub struct EditVoiceBody {
     name: String,
     files: Option<Vec<String>>,
+    samples: Vec<FileUpload>,
     description: Option<String>,
     labels: Option<Vec<(String, String)>>,
+    remove_background_noise: Option<bool>,
 }
 
 impl EditVoiceBody {
@@ -481,14 +821,24 @@ impl EditVoiceBody {
         Self {
             name: name.to_string(),
             files: None,
+            samples: Vec::new(),
             description: None,
             labels: None,
+            remove_background_noise: None,
         }
     }
     pub fn with_files(mut self, files: Vec<String>) -> Self {
         self.files = Some(files);
         self
     }
+    pub fn with_samples(mut self, samples: Vec<FileUpload>) -> Self {
+        self.samples.extend(samples);
+        self
+    }
+    pub fn with_remove_background_noise(mut self, remove_background_noise: bool) -> Self {
+        self.remove_background_noise = Some(remove_background_noise);
+        self
+    }
     pub fn with_description(mut self, description: &str) -> Self {
         self.description = Some(description.to_string());
         self
@@ -500,7 +850,8 @@ impl EditVoiceBody {
 }
 
 /// Get all voices response body
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct VoicesResponseBody {
     voices: Vec<VoiceResponseBody>,
 }
@@ -511,9 +862,23 @@ impl VoicesResponseBody {
     }
 }
 
+/// Voices with a preview clip, see
+/// [ElevenLabsClient::fetch_preview](crate::ElevenLabsClient::fetch_preview)
+pub trait Previewable {
+    /// The URL of the preview clip, `None` if the voice has none
+    fn preview_url(&self) -> Option<&str>;
+}
+
+impl Previewable for VoiceResponseBody {
+    fn preview_url(&self) -> Option<&str> {
+        self.preview_url.as_deref()
+    }
+}
+
 // TODO: update this
 /// Voice response body
-#[derive(Debug, Deserialize, PartialEq, Clone)]
+#[derive(Debug, Deserialize, PartialEq, Clone, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct VoiceResponseBody {
     voice_id: String,
     name: String,
@@ -523,10 +888,173 @@ pub struct VoiceResponseBody {
     description: Option<String>,
     preview_url: Option<String>,
     settings: Option<VoiceSettings>,
+    fine_tuning: Option<FineTuning>,
+}
+
+/// Fine-tuning state of a voice, including PVC verification progress
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct FineTuning {
+    is_allowed_to_fine_tune: Option<bool>,
+    state: Option<HashMap<String, String>>,
+    verification_failures: Option<Vec<String>>,
+    verification_attempts_count: Option<u32>,
+    manual_verification_requested: Option<bool>,
+    language: Option<String>,
+    verification_attempts: Option<Vec<VerificationAttempt>>,
+    manual_verification: Option<ManualVerification>,
+    max_verification_attempts: Option<u32>,
+    next_max_verification_attempts_reset_unix_ms: Option<u64>,
+}
+
+impl FineTuning {
+    pub fn is_allowed_to_fine_tune(&self) -> Option<bool> {
+        self.is_allowed_to_fine_tune
+    }
+    /// Fine-tuning state per model ID
+    pub fn state(&self) -> Option<&HashMap<String, String>> {
+        self.state.as_ref()
+    }
+    pub fn verification_failures(&self) -> Option<&Vec<String>> {
+        self.verification_failures.as_ref()
+    }
+    pub fn verification_attempts_count(&self) -> Option<u32> {
+        self.verification_attempts_count
+    }
+    pub fn manual_verification_requested(&self) -> Option<bool> {
+        self.manual_verification_requested
+    }
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+    pub fn verification_attempts(&self) -> Option<&Vec<VerificationAttempt>> {
+        self.verification_attempts.as_ref()
+    }
+    pub fn manual_verification(&self) -> Option<&ManualVerification> {
+        self.manual_verification.as_ref()
+    }
+    pub fn max_verification_attempts(&self) -> Option<u32> {
+        self.max_verification_attempts
+    }
+    pub fn next_max_verification_attempts_reset_unix_ms(&self) -> Option<u64> {
+        self.next_max_verification_attempts_reset_unix_ms
+    }
+}
+
+/// A captcha verification attempt
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct VerificationAttempt {
+    text: String,
+    date_unix: u64,
+    accepted: bool,
+    similarity: f32,
+    levenshtein_distance: f32,
+    recording: Option<Recording>,
+}
+
+impl VerificationAttempt {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    pub fn date_unix(&self) -> u64 {
+        self.date_unix
+    }
+    pub fn accepted(&self) -> bool {
+        self.accepted
+    }
+    pub fn similarity(&self) -> f32 {
+        self.similarity
+    }
+    pub fn levenshtein_distance(&self) -> f32 {
+        self.levenshtein_distance
+    }
+    pub fn recording(&self) -> Option<&Recording> {
+        self.recording.as_ref()
+    }
+}
+
+/// The recording submitted for a verification attempt
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Recording {
+    recording_id: String,
+    mime_type: String,
+    size_bytes: u64,
+    upload_date_unix: u64,
+    transcription: String,
+}
+
+impl Recording {
+    pub fn recording_id(&self) -> &str {
+        &self.recording_id
+    }
+    pub fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+    pub fn size_bytes(&self) -> u64 {
+        self.size_bytes
+    }
+    pub fn upload_date_unix(&self) -> u64 {
+        self.upload_date_unix
+    }
+    pub fn transcription(&self) -> &str {
+        &self.transcription
+    }
+}
+
+/// A manual verification request and the files uploaded with it
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ManualVerification {
+    extra_text: String,
+    request_time_unix: u64,
+    files: Vec<ManualVerificationFile>,
+}
+
+impl ManualVerification {
+    pub fn extra_text(&self) -> &str {
+        &self.extra_text
+    }
+    pub fn request_time_unix(&self) -> u64 {
+        self.request_time_unix
+    }
+    pub fn files(&self) -> &Vec<ManualVerificationFile> {
+        &self.files
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ManualVerificationFile {
+    file_id: String,
+    file_name: String,
+    mime_type: String,
+    size_bytes: u64,
+    upload_date_unix: u64,
+}
+
+impl ManualVerificationFile {
+    pub fn file_id(&self) -> &str {
+        &self.file_id
+    }
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+    pub fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+    pub fn size_bytes(&self) -> u64 {
+        self.size_bytes
+    }
+    pub fn upload_date_unix(&self) -> u64 {
+        self.upload_date_unix
+    }
 }
 
 /// Voice sample
-#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[derive(Deserialize, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct VoiceSample {
     sample_id: String,
     file_name: String,
@@ -555,6 +1083,7 @@ impl VoiceSample {
 
 /// Voice settings
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct VoiceSettings {
     similarity_boost: f32,
     stability: f32,
@@ -562,6 +1091,8 @@ pub struct VoiceSettings {
     style: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     use_speaker_boost: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    speed: Option<f32>,
 }
 
 impl VoiceSettings {
@@ -571,6 +1102,7 @@ impl VoiceSettings {
             stability,
             style: None,
             use_speaker_boost: None,
+            speed: None,
         }
     }
     pub fn with_style(mut self, style: f32) -> Self {
@@ -581,6 +1113,10 @@ impl VoiceSettings {
         self.use_speaker_boost = Some(use_speaker_boost);
         self
     }
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = Some(speed);
+        self
+    }
 
     pub fn similarity_boost(&self) -> f32 {
         self.similarity_boost
@@ -597,6 +1133,10 @@ impl VoiceSettings {
     pub fn use_speaker_boost(&self) -> Option<bool> {
         self.use_speaker_boost
     }
+
+    pub fn speed(&self) -> Option<f32> {
+        self.speed
+    }
 }
 
 impl Default for VoiceSettings {
@@ -606,6 +1146,7 @@ impl Default for VoiceSettings {
             stability: 0.5,
             style: Some(0.5),
             use_speaker_boost: Some(true),
+            speed: None,
         }
     }
 }
@@ -635,43 +1176,45 @@ impl VoiceResponseBody {
     pub fn get_settings(&self) -> Option<&VoiceSettings> {
         self.settings.as_ref()
     }
+    pub fn get_fine_tuning(&self) -> Option<&FineTuning> {
+        self.fine_tuning.as_ref()
+    }
 }
 
 fn to_multipart<P: AsRef<Path>>(
     voice_name: String,
     file_paths: Option<Vec<P>>,
+    samples: &[FileUpload],
     description: Option<String>,
     labels: Option<Vec<(String, String)>>,
+    remove_background_noise: Option<bool>,
 ) -> Result<Form> {
     let mut form = Form::new();
     form = form.text("name", voice_name);
 
     if let Some(file_paths) = file_paths {
         for file_path in file_paths {
-            let fp = file_path.as_ref();
-            let audio_bytes = std::fs::read(fp)?;
-            let mut part = Part::bytes(audio_bytes);
-            let file_path_str = fp.to_str().ok_or(Box::new(Error::PathNotValidUTF8))?;
-            part = part.file_name(file_path_str.to_string());
-            let mime_subtype = fp
-                .extension()
-                .ok_or(Box::new(Error::FileExtensionNotFound))?
-                .to_str()
-                .ok_or(Box::new(Error::FileExtensionNotValidUTF8))?;
-            let mime = format!("audio/{}", mime_subtype);
-            part = part.mime_str(&mime)?;
-            form = form.part("files", part);
+            form = form.part("files", FileUpload::from_path(file_path)?.to_part()?);
         }
-        if let Some(description) = description {
-            form = form.text("description", description)
-        }
-        if let Some(labels) = labels {
-            let mut label_map = HashMap::new();
-            for (k, v) in labels {
-                label_map.insert(k, v);
-            }
-            form = form.text("labels", serde_json::to_string(&label_map)?)
+    }
+    for sample in samples {
+        form = form.part("files", sample.to_part()?);
+    }
+    if let Some(description) = description {
+        form = form.text("description", description)
+    }
+    if let Some(labels) = labels {
+        let mut label_map = HashMap::new();
+        for (k, v) in labels {
+            label_map.insert(k, v);
         }
+        form = form.text("labels", serde_json::to_string(&label_map)?)
+    }
+    if let Some(remove_background_noise) = remove_background_noise {
+        form = form.text(
+            "remove_background_noise",
+            remove_background_noise.to_string(),
+        )
     }
     Ok(form)
 }
@@ -72,6 +72,7 @@
 
 use std::collections::HashMap;
 use super::*;
+use crate::error::VoiceDesignError;
 
 const CREATE_PREVIEW_PATH: &str = "/v1/text-to-voice/create-previews";
 const CREATE_VOICE_FROM_PREVIEW_PATH: &str = "/v1/text-to-voice/create-voice-from-preview";
@@ -101,7 +102,11 @@ const CREATE_VOICE_FROM_PREVIEW_PATH: &str = "/v1/text-to-voice/create-voice-fro
 ///      "Mwahahaha, marvel at my magic ye mortals! /
 ///       My incantation masters sound everywhere I go!
 ///       Mwahahaha, Mwahahaha",
-///     );
+///     )
+///     // The same seed gives the same previews, to tune the other settings against
+///     .with_seed(42)
+///     .with_guidance_scale(8.0)
+///     .with_loudness(0.7);
 ///
 ///     let voice_previews = c.hit(CreatePreviews::new(body)).await?;
 ///
@@ -125,18 +130,85 @@ impl CreatePreviews {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+/// `loudness` and `quality` expected to be between -1.0 and 1.0, `guidance_scale`
+/// between 0.0 and 100.0
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct CreatePreviewsBody {
     text: String,
     voice_description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_generate_text: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    loudness: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quality: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    guidance_scale: Option<f32>,
 }
 
 impl CreatePreviewsBody {
     pub fn new<T: Into<String>>(voice_description: T, text: T) -> Self {
         Self {
             text: text.into(),
-            voice_description: voice_description.into()
+            voice_description: voice_description.into(),
+            auto_generate_text: None,
+            loudness: None,
+            quality: None,
+            seed: None,
+            guidance_scale: None,
+        }
+    }
+
+    /// Let the API write a text that suits the voice description, `text` is then ignored
+    pub fn with_auto_generate_text(mut self, auto_generate_text: bool) -> Self {
+        self.auto_generate_text = Some(auto_generate_text);
+        self
+    }
+
+    /// How loud the previews are, from -1.0 (quietest) to 1.0 (loudest), the API
+    /// default is 0.5
+    pub fn with_loudness(mut self, loudness: f32) -> Self {
+        self.loudness = Some(loudness);
+        self
+    }
+
+    /// Trade variety for quality, from -1.0 to 1.0, the API default is 0.9
+    pub fn with_quality(mut self, quality: f32) -> Self {
+        self.quality = Some(quality);
+        self
+    }
+
+    /// A seed so the same description and settings give the same previews
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// How closely the previews follow the description, from 0.0 to 100.0, the API
+    /// default is 5.0. High values can sound robotic.
+    pub fn with_guidance_scale(mut self, guidance_scale: f32) -> Self {
+        self.guidance_scale = Some(guidance_scale);
+        self
+    }
+
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Check the settings before the request is sent
+    pub fn validate(&self) -> std::result::Result<(), VoiceDesignError> {
+        if let Some(loudness) = self.loudness.filter(|l| !(-1.0..=1.0).contains(l)) {
+            return Err(VoiceDesignError::LoudnessOutOfRange(loudness));
+        }
+        if let Some(quality) = self.quality.filter(|q| !(-1.0..=1.0).contains(q)) {
+            return Err(VoiceDesignError::QualityOutOfRange(quality));
+        }
+        if let Some(scale) = self.guidance_scale.filter(|g| !(0.0..=100.0).contains(g)) {
+            return Err(VoiceDesignError::GuidanceScaleOutOfRange(scale));
         }
+        Ok(())
     }
 }
 
@@ -148,6 +220,7 @@ impl Endpoint for CreatePreviews {
     }
 
     fn request_body(&self) -> Result<RequestBody> {
+        self.0.validate()?;
         Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
     }
 
@@ -161,7 +234,8 @@ impl Endpoint for CreatePreviews {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CreatePreviewsResponse {
     previews: Vec<VoicePreview>
 }
@@ -174,7 +248,8 @@ impl Iterator for CreatePreviewsResponse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct VoicePreview {
     audio_base_64: String,
     generated_voice_id: String,
@@ -241,7 +316,7 @@ impl CreateVoiceFromPreview {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct CreateVoiceFromPreviewBody {
     voice_name: String,
     voice_description: String,
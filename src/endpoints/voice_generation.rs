@@ -205,7 +205,8 @@ impl Endpoint for GetGenerationParams {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 #[deprecated]
 pub struct VoiceGenerationParamsResponse {
     genders: Vec<VoiceGenerationParams>,
@@ -218,7 +219,8 @@ pub struct VoiceGenerationParamsResponse {
 }
 
 #[allow(dead_code)]
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 #[deprecated]
 pub struct VoiceGenerationParams {
     name: String,
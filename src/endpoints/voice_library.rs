@@ -12,10 +12,12 @@
 //! - age
 //! - accent
 //! - language
+//! - locale
 //! - search
 //! - use cases
 //! - descriptives
 //! - featured
+//! - min/max notice period in days
 //! - rendered app enabled
 //! - owner ID
 //! - sort
@@ -54,7 +56,11 @@
 //! }
 //! ```
 use super::*;
+use crate::client::ElevenLabsClient;
+use crate::endpoints::voice::Previewable;
 pub use crate::endpoints::voice_generation::Age;
+use async_stream::try_stream;
+use futures_util::Stream;
 const SHARED_VOICES_PATH: &str = "/v1/shared-voices";
 const PAGE_SIZE_QUERY: &str = "page_size";
 const CATEGORY_QUERY: &str = "category";
@@ -62,10 +68,13 @@ const GENDER_QUERY: &str = "gender";
 const AGE_QUERY: &str = "age";
 const ACCENT_QUERY: &str = "accent";
 const LANGUAGE_QUERY: &str = "language";
+const LOCALE_QUERY: &str = "locale";
 const SEARCH_QUERY: &str = "search";
 const USE_CASES_QUERY: &str = "use_cases";
 const DESCRIPTIVES_QUERY: &str = "descriptives";
 const FEATURED_QUERY: &str = "featured";
+const MIN_NOTICE_PERIOD_DAYS_QUERY: &str = "min_notice_period_days";
+const MAX_NOTICE_PERIOD_DAYS_QUERY: &str = "max_notice_period_days";
 const RENDERED_APP_ENABLED_QUERY: &str = "rendered_app_enabled";
 const OWNER_ID_QUERY: &str = "owner_id";
 const SORT_QUERY: &str = "sort";
@@ -123,8 +132,54 @@ impl Endpoint for GetSharedVoices {
     }
 }
 
+/// Page through the shared voices matching `query`, yielding each voice in turn.
+///
+/// Starts from the query's page (or the first page) and stops once the API reports no more results.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let query = SharedVoicesQuery::default()
+///         .with_page_size(100)
+///         .with_language("en")
+///         .with_locale("en-GB");
+///     let voices = paginate_shared_voices(&c, query);
+///     pin_mut!(voices);
+///     while let Some(voice) = voices.next().await {
+///         println!("{}", voice?.name());
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn paginate_shared_voices(
+    client: &ElevenLabsClient,
+    query: SharedVoicesQuery,
+) -> impl Stream<Item = Result<SharedVoice>> + '_ {
+    let mut query = query;
+    let mut page = query.page.unwrap_or(0);
+    try_stream! {
+        loop {
+            query.page = Some(page);
+            let endpoint = GetSharedVoices::new(query.clone());
+            let resp = client.hit(endpoint).await?;
+            for voice in resp.voices {
+                yield voice;
+            }
+            if !resp.has_more {
+                break;
+            }
+            page += 1;
+        }
+    }
+}
+
 /// Shared voices response
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct SharedVoicesResponse {
     voices: Vec<SharedVoice>,
     has_more: bool,
@@ -144,7 +199,8 @@ impl SharedVoicesResponse {
 }
 
 /// Shared voice
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct SharedVoice {
     public_owner_id: String,
     voice_id: String,
@@ -174,6 +230,12 @@ pub struct SharedVoice {
     tiktok_username: Option<String>,
 }
 
+impl Previewable for SharedVoice {
+    fn preview_url(&self) -> Option<&str> {
+        (!self.preview_url.is_empty()).then_some(self.preview_url.as_str())
+    }
+}
+
 impl SharedVoice {
     pub fn public_owner_id(&self) -> &str {
         &self.public_owner_id
@@ -251,49 +313,56 @@ pub struct SharedVoicesQuery {
     pub age: Option<String>,
     pub accent: Option<String>,
     pub language: Option<String>,
+    pub locale: Option<String>,
     pub search: Option<String>,
     pub use_cases: Option<String>,
     pub descriptives: Option<String>,
     pub featured: Option<String>,
+    pub min_notice_period_days: Option<String>,
+    pub max_notice_period_days: Option<String>,
     pub rendered_app_enabled: Option<String>,
     pub owner_id: Option<String>,
     pub sort: Option<String>,
-    pub page: Option<String>,
+    pub page: Option<u32>,
 }
 
 impl SharedVoicesQuery {
     pub fn with_page_size(mut self, page_size: u16) -> Self {
-        self.page_size = Some(format!("{}={}", PAGE_SIZE_QUERY, page_size));
+        self.page_size = Some(query_pair(PAGE_SIZE_QUERY, page_size));
         self
     }
     pub fn with_category(mut self, category: Category) -> Self {
-        self.category = Some(format!("{}={}", CATEGORY_QUERY, category.as_str()));
+        self.category = Some(query_pair(CATEGORY_QUERY, category.as_str()));
         self
     }
     pub fn with_gender(mut self, gender: Gender) -> Self {
-        self.gender = Some(format!("{}={}", GENDER_QUERY, gender.as_str()));
+        self.gender = Some(query_pair(GENDER_QUERY, gender.as_str()));
         self
     }
     pub fn with_age(mut self, age: Age) -> Self {
-        self.age = Some(format!("{}={}", AGE_QUERY, age.as_str()));
+        self.age = Some(query_pair(AGE_QUERY, age.as_str()));
         self
     }
     pub fn with_accent(mut self, accent: &str) -> Self {
-        self.accent = Some(format!("{}={}", ACCENT_QUERY, accent));
+        self.accent = Some(query_pair(ACCENT_QUERY, accent));
         self
     }
     pub fn with_language(mut self, language: &str) -> Self {
-        self.language = Some(format!("{}={}", LANGUAGE_QUERY, language));
+        self.language = Some(query_pair(LANGUAGE_QUERY, language));
+        self
+    }
+    pub fn with_locale(mut self, locale: &str) -> Self {
+        self.locale = Some(query_pair(LOCALE_QUERY, locale));
         self
     }
     pub fn with_search(mut self, search: &str) -> Self {
-        self.search = Some(format!("{}={}", SEARCH_QUERY, search));
+        self.search = Some(query_pair(SEARCH_QUERY, search));
         self
     }
     pub fn with_use_cases(mut self, use_cases: Vec<String>) -> Self {
         let use_cases_formatted = use_cases
             .iter()
-            .map(|use_case| format!("{}={}", USE_CASES_QUERY, use_case))
+            .map(|use_case| query_pair(USE_CASES_QUERY, use_case))
             .collect::<Vec<String>>()
             .join("&");
         self.use_cases = Some(use_cases_formatted);
@@ -302,33 +371,39 @@ impl SharedVoicesQuery {
     pub fn with_descriptives(mut self, descriptives: Vec<String>) -> Self {
         let descriptives_formatted = descriptives
             .iter()
-            .map(|descriptive| format!("{}={}", DESCRIPTIVES_QUERY, descriptive))
+            .map(|descriptive| query_pair(DESCRIPTIVES_QUERY, descriptive))
             .collect::<Vec<String>>()
             .join("&");
         self.descriptives = Some(descriptives_formatted);
         self
     }
     pub fn with_featured(mut self, featured: bool) -> Self {
-        self.featured = Some(format!("{}={}", FEATURED_QUERY, featured));
+        self.featured = Some(query_pair(FEATURED_QUERY, featured));
+        self
+    }
+    pub fn with_min_notice_period_days(mut self, days: u32) -> Self {
+        self.min_notice_period_days = Some(query_pair(MIN_NOTICE_PERIOD_DAYS_QUERY, days));
+        self
+    }
+    pub fn with_max_notice_period_days(mut self, days: u32) -> Self {
+        self.max_notice_period_days = Some(query_pair(MAX_NOTICE_PERIOD_DAYS_QUERY, days));
         self
     }
     pub fn with_rendered_app_enabled(mut self, rendered_app_enabled: bool) -> Self {
-        self.rendered_app_enabled = Some(format!(
-            "{}={}",
-            RENDERED_APP_ENABLED_QUERY, rendered_app_enabled
-        ));
+        self.rendered_app_enabled =
+            Some(query_pair(RENDERED_APP_ENABLED_QUERY, rendered_app_enabled));
         self
     }
     pub fn with_owner_id(mut self, owner_id: &str) -> Self {
-        self.owner_id = Some(format!("{}={}", OWNER_ID_QUERY, owner_id));
+        self.owner_id = Some(query_pair(OWNER_ID_QUERY, owner_id));
         self
     }
     pub fn with_sort(mut self, sort: &str) -> Self {
-        self.sort = Some(format!("{}={}", SORT_QUERY, sort));
+        self.sort = Some(query_pair(SORT_QUERY, sort));
         self
     }
     pub fn with_page(mut self, page: u16) -> Self {
-        self.page = Some(format!("{}={}", PAGE_QUERY, page));
+        self.page = Some(page.into());
         self
     }
 
@@ -368,6 +443,12 @@ impl SharedVoicesQuery {
             }
             result.push_str(&value);
         }
+        if let Some(value) = self.locale.as_ref() {
+            if !result.is_empty() {
+                result.push('&');
+            }
+            result.push_str(value);
+        }
         if let Some(value) = self.search.as_ref() {
             if !result.is_empty() {
                 result.push('&');
@@ -392,6 +473,18 @@ impl SharedVoicesQuery {
             }
             result.push_str(&value);
         }
+        if let Some(value) = self.min_notice_period_days.as_ref() {
+            if !result.is_empty() {
+                result.push('&');
+            }
+            result.push_str(value);
+        }
+        if let Some(value) = self.max_notice_period_days.as_ref() {
+            if !result.is_empty() {
+                result.push('&');
+            }
+            result.push_str(value);
+        }
         if let Some(value) = self.rendered_app_enabled.as_ref() {
             if !result.is_empty() {
                 result.push('&');
@@ -410,11 +503,11 @@ impl SharedVoicesQuery {
             }
             result.push_str(&value);
         }
-        if let Some(value) = self.page.as_ref() {
+        if let Some(page) = self.page {
             if !result.is_empty() {
                 result.push('&');
             }
-            result.push_str(&value);
+            result.push_str(&query_pair(PAGE_QUERY, page));
         }
         result
     }
@@ -439,6 +532,7 @@ impl Gender {
 
 #[derive(Clone, Debug)]
 pub enum Category {
+    Famous,
     Generated,
     HighQuality,
     Professional,
@@ -447,6 +541,7 @@ pub enum Category {
 impl Category {
     pub fn as_str(&self) -> &str {
         match self {
+            Category::Famous => "famous",
             Category::Generated => "generated",
             Category::HighQuality => "high_quality",
             Category::Professional => "professional",
@@ -538,11 +633,19 @@ impl Endpoint for AddSharedVoice {
 }
 
 /// Response for adding a shared voice
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct AddSharedVoiceResponse {
     voice_id: String,
 }
 
+impl AddSharedVoiceResponse {
+    /// The ID of the voice as added to your collection
+    pub fn voice_id(&self) -> &str {
+        &self.voice_id
+    }
+}
+
 /// Parameters for adding a shared voice
 #[derive(Clone, Debug)]
 pub struct AddSharedVoiceParams {
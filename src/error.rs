@@ -12,12 +12,25 @@ pub enum Error {
     FileExtensionNotValidUTF8,
     #[error("FileExtensionNotSupported")]
     FileExtensionNotSupported,
+    #[error("OutputFormatNotSupported: {0}")]
+    OutputFormatNotSupported(String),
     #[error("PathNotValidUTF8")]
     PathNotValidUTF8,
     #[error("VoiceNotFound")]
     VoiceNotFound,
+    #[error("PreviewNotFound")]
+    PreviewNotFound,
+    #[error("InvalidApiPath: {0}")]
+    InvalidApiPath(String),
     #[error("GeneratedVoiceIDHeaderNotFound")]
     GeneratedVoiceIDHeaderNotFound,
+    #[error("InvalidZipArchive: {0}")]
+    InvalidZipArchive(String),
+    #[error("DubbingTimeout: {0}")]
+    DubbingTimeout(String),
+    /// A streamed upload was already sent, and can't be read again for a retry
+    #[error("UploadStreamConsumed")]
+    UploadStreamConsumed,
 }
 
 #[derive(Error, Debug, Deserialize)]
@@ -33,6 +46,16 @@ pub struct Detail {
     status: String,
 }
 
+impl Detail {
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+    /// The machine readable error, e.g. `quota_exceeded` or `invalid_api_key`
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 pub struct DetailObject {
@@ -54,16 +77,69 @@ pub enum ElevenLabsClientError {
     Code4xx { detail: String },
 }
 
+impl ElevenLabsClientError {
+    /// The error detail, for errors that have one
+    pub fn detail(&self) -> Option<&Detail> {
+        match self {
+            ElevenLabsClientError::BadRequest { detail }
+            | ElevenLabsClientError::NotFound { detail } => Some(detail),
+            _ => None,
+        }
+    }
+}
+
+/// Errors from a websocket connection, with close codes the API uses told apart
 #[derive(Error, Debug)]
 pub enum WebSocketError {
+    /// Any close code without a variant of its own, with the close reason
     #[error("NonNormalCloseCode: {0}")]
     NonNormalCloseCode(String),
+    /// Closed with 1008, e.g. for an invalid API key or an exceeded quota
+    #[error("PolicyViolation: {0}")]
+    PolicyViolation(String),
+    /// Closed with 1011 after an error on the server
+    #[error("InternalServerError: {0}")]
+    InternalServerError(String),
+    /// Closed with 1013 when the server is at capacity
+    #[error("TryAgainLater: {0}")]
+    TryAgainLater(String),
     #[error("ClosedWithoutCloseFrame")]
     ClosedWithoutCloseFrame,
+    /// The connection wasn't established within the client's connect timeout
+    #[error("ConnectTimeout")]
+    ConnectTimeout,
     #[error("UnexpectedMessageType")]
     UnexpectedMessageType,
 }
 
+impl WebSocketError {
+    /// The error for a close frame with a code other than 1000
+    pub(crate) fn from_close(code: u16, reason: String) -> Self {
+        match code {
+            1008 => WebSocketError::PolicyViolation(reason),
+            1011 => WebSocketError::InternalServerError(reason),
+            1013 => WebSocketError::TryAgainLater(reason),
+            _ => WebSocketError::NonNormalCloseCode(reason),
+        }
+    }
+    /// Whether the connection was closed because the quota is used up
+    pub fn is_quota_exceeded(&self) -> bool {
+        match self {
+            WebSocketError::PolicyViolation(reason) => reason.to_lowercase().contains("quota"),
+            _ => false,
+        }
+    }
+    /// Whether retrying the connection later may succeed
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            WebSocketError::InternalServerError(_)
+                | WebSocketError::TryAgainLater(_)
+                | WebSocketError::ConnectTimeout
+        )
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum ConvAIError {
     #[error("JSON deserialization error: {0}")]
@@ -74,4 +150,134 @@ pub enum ConvAIError {
     UnknownResponseType(String),
     #[error("WebSocket message error: {0}")]
     WebSocketError(String),
+    #[error("WebSocket closed: {0}")]
+    Closed(#[from] WebSocketError),
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum DialogueError {
+    #[error("Dialogue has no inputs")]
+    NoInputs,
+    #[error("Dialogue input {0} has no text")]
+    EmptyText(usize),
+    #[error("Dialogue input {0} has no voice ID")]
+    EmptyVoiceID(usize),
+    #[error("Dialogue input {index} has an unbalanced audio tag bracket at byte {position}")]
+    UnbalancedTag { index: usize, position: usize },
+    #[error("Dialogue input {0} has an empty audio tag")]
+    EmptyTag(usize),
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum DubbingError {
+    #[error("Dub has no file or source URL")]
+    NoSource,
+    #[error("Dub has more than one of a file, file upload and source URL")]
+    MultipleSources,
+    #[error("Dub has no target language")]
+    NoTargetLanguage,
+    #[error("Manual mode is only supported for dubbing studio projects")]
+    ManualModeWithoutStudio,
+    #[error("Dub time {0} is not a non-negative number of seconds")]
+    InvalidTime(f32),
+    #[error("Dub start time {start} is not before end time {end}")]
+    EmptyTimeRange { start: f32, end: f32 },
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum VoiceDesignError {
+    #[error("Loudness {0} is outside -1.0 to 1.0")]
+    LoudnessOutOfRange(f32),
+    #[error("Quality {0} is outside -1.0 to 1.0")]
+    QualityOutOfRange(f32),
+    #[error("Guidance scale {0} is outside 0.0 to 100.0")]
+    GuidanceScaleOutOfRange(f32),
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum SoundGenerationError {
+    #[error("Sound generation text is empty")]
+    EmptyText,
+    #[error("Duration of {0} seconds is outside 0.5 to 22 seconds")]
+    DurationOutOfRange(f64),
+    #[error("Prompt influence {0} is outside 0.0 to 1.0")]
+    PromptInfluenceOutOfRange(f64),
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum QuotaError {
+    #[error("Request needs {required} credits but only {remaining} remain")]
+    Exceeded { required: u64, remaining: i64 },
+}
+
+/// An error the API sends inside a stream after the response has started
+///
+/// Streaming endpoints return this instead of the chunk, so it can be told apart from
+/// transport errors such as a connection reset.
+#[derive(Clone, Debug, Error, PartialEq)]
+#[error("ApiError: {status}: {message}")]
+pub struct ApiError {
+    status: String,
+    message: String,
+}
+
+impl ApiError {
+    pub(crate) fn new(status: &str, message: &str) -> Self {
+        ApiError {
+            status: status.to_string(),
+            message: message.to_string(),
+        }
+    }
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+    pub fn is_quota_exceeded(&self) -> bool {
+        self.status == "quota_exceeded"
+    }
+    /// Parse a chunk that is a JSON error frame, `{"detail": ..}` or `{"error": ..}`.
+    ///
+    /// Returns `None` for anything else, including audio.
+    pub(crate) fn from_frame(chunk: &[u8]) -> Option<ApiError> {
+        let trimmed = chunk.trim_ascii_start();
+        if !trimmed.starts_with(b"{") {
+            return None;
+        }
+        let value = serde_json::from_slice::<Value>(trimmed).ok()?;
+        let inner = value.get("detail").or_else(|| value.get("error"))?;
+        Some(match inner {
+            Value::String(message) => ApiError {
+                status: string_field(&value, "status").unwrap_or_else(|| "error".to_string()),
+                message: message.clone(),
+            },
+            Value::Object(_) => ApiError {
+                status: string_field(inner, "status")
+                    .or_else(|| string_field(inner, "code"))
+                    .unwrap_or_else(|| "error".to_string()),
+                message: string_field(inner, "message").unwrap_or_else(|| inner.to_string()),
+            },
+            other => ApiError {
+                status: "error".to_string(),
+                message: other.to_string(),
+            },
+        })
+    }
+}
+
+fn string_field(value: &Value, key: &str) -> Option<String> {
+    value.get(key)?.as_str().map(str::to_string)
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum WebhookError {
+    #[error("Missing ElevenLabs-Signature header")]
+    MissingSignature,
+    #[error("Malformed ElevenLabs-Signature header")]
+    MalformedSignature,
+    #[error("Webhook timestamp is outside the tolerance window")]
+    TimestampOutOfTolerance,
+    #[error("Webhook signature does not match")]
+    InvalidSignature,
 }
@@ -26,8 +26,20 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! # Features
+//!
+//! - `playback` (default): audio playback helpers in [utils].
+//! - `strict`: response models reject unknown fields instead of ignoring them,
+//!   so changes to the ElevenLabs API surface as deserialization errors.
+//!   Meant for testing and CI, not production.
+//! - `cache`: an in-memory cache for GET responses in `cache`.
+//! - `webhooks`: webhook signature verification and payloads in `webhooks`.
 
-pub use crate::client::{ElevenLabsClient, Result};
+pub use crate::api_keys::{ApiKeyProvider, Failover, RoundRobin};
+pub use crate::client::{
+    ClientBuilder, DefaultSettings, ElevenLabsClient, ResponseEnvelope, Result,
+};
 #[cfg(feature = "dev")]
 pub use crate::convai_client::ConvAIClient;
 pub use crate::endpoints::audio_isolation::*;
@@ -37,9 +49,13 @@ pub use crate::endpoints::history::*;
 pub use crate::endpoints::models::*;
 pub use crate::endpoints::projects::*;
 pub use crate::endpoints::pronunciation::*;
+pub use crate::endpoints::pvc::*;
 pub use crate::endpoints::samples::*;
 pub use crate::endpoints::sound_generation::*;
+pub use crate::endpoints::speech_to_text::*;
+pub use crate::endpoints::speech_to_text::realtime::*;
 pub use crate::endpoints::sts::*;
+pub use crate::endpoints::text_to_dialogue::*;
 pub use crate::endpoints::tts::*;
 pub use crate::endpoints::tts::ws::*;
 pub use crate::endpoints::user::*;
@@ -47,15 +63,22 @@ pub use crate::endpoints::voice::*;
 pub use crate::endpoints::voice_design::*;
 pub use crate::endpoints::voice_generation::*;
 pub use crate::endpoints::voice_library::*;
-pub use crate::shared::identifiers::{Model, PreMadeVoiceID};
+pub use crate::shared::identifiers::{Language, Model, PreMadeVoiceID, VoiceID};
 pub use crate::shared::query_params::*;
+pub use crate::shared::uploads::FileUpload;
 pub use bytes::Bytes;
 pub use futures_util::{pin_mut, StreamExt};
+pub use tokio_tungstenite::Connector;
 
+mod api_keys;
+#[cfg(feature = "cache")]
+pub mod cache;
 mod client;
 pub mod endpoints;
 pub mod error;
 mod shared;
 pub mod utils;
+#[cfg(feature = "webhooks")]
+pub mod webhooks;
 #[cfg(feature = "dev")]
 mod convai_client;
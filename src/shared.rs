@@ -2,7 +2,7 @@ pub mod identifiers {
     use crate::endpoints::Deserialize;
     use serde::Serialize;
 
-    #[derive(Clone, Debug, Deserialize)]
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
     pub(crate) struct HistoryItemID(pub(crate) String);
 
     impl From<String> for HistoryItemID {
@@ -21,7 +21,31 @@ pub mod identifiers {
         }
     }
 
-    #[derive(Clone, Debug)]
+    impl ModelID {
+        pub(crate) fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+    }
+
+    /// A model ID
+    ///
+    /// Converts to and from the raw ID with [Display](std::fmt::Display) and
+    /// [FromStr](std::str::FromStr). IDs without a variant of their own parse to
+    /// [Model::Custom], so parsing never fails.
+    ///
+    /// # Example
+    /// ```
+    /// use elevenlabs_rs::Model;
+    ///
+    /// let model: Model = "eleven_v3".parse().unwrap();
+    /// assert_eq!(model, Model::ElevenV3);
+    /// assert_eq!(model.to_string(), "eleven_v3");
+    ///
+    /// let model: Model = "eleven_v4_preview".parse().unwrap();
+    /// assert_eq!(model, Model::Custom("eleven_v4_preview".to_string()));
+    /// ```
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
     pub enum Model {
         ElevenMultilingualV2,
         ElevenMultilingualV1,
@@ -30,24 +54,700 @@ pub mod identifiers {
         ElevenTurboV2,
         ElevenMultilingualV2STS,
         ElevenTurboV2Dot5,
+        ElevenFlashV2,
+        ElevenFlashV2Dot5,
+        ElevenV3,
+        /// Any other model ID, e.g. one returned by [GetModels](crate::endpoints::models::GetModels)
+        Custom(String),
+    }
+
+    impl Model {
+        pub fn as_str(&self) -> &str {
+            match self {
+                Model::ElevenMultilingualV2 => "eleven_multilingual_v2",
+                Model::ElevenMultilingualV1 => "eleven_multilingual_v1",
+                Model::ElevenEnglishV1 => "eleven_monolingual_v1",
+                Model::ElevenEnglishV2 => "eleven_english_sts_v2",
+                Model::ElevenTurboV2 => "eleven_turbo_v2",
+                Model::ElevenMultilingualV2STS => "eleven_multilingual_sts_v2",
+                Model::ElevenTurboV2Dot5 => "eleven_turbo_v2_5",
+                Model::ElevenFlashV2 => "eleven_flash_v2",
+                Model::ElevenFlashV2Dot5 => "eleven_flash_v2_5",
+                Model::ElevenV3 => "eleven_v3",
+                Model::Custom(model_id) => model_id,
+            }
+        }
+    }
+
+    impl std::fmt::Display for Model {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+
+    impl std::str::FromStr for Model {
+        type Err = std::convert::Infallible;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Ok(match s {
+                "eleven_multilingual_v2" => Model::ElevenMultilingualV2,
+                "eleven_multilingual_v1" => Model::ElevenMultilingualV1,
+                "eleven_monolingual_v1" => Model::ElevenEnglishV1,
+                "eleven_english_sts_v2" => Model::ElevenEnglishV2,
+                "eleven_turbo_v2" => Model::ElevenTurboV2,
+                "eleven_multilingual_sts_v2" => Model::ElevenMultilingualV2STS,
+                "eleven_turbo_v2_5" => Model::ElevenTurboV2Dot5,
+                "eleven_flash_v2" => Model::ElevenFlashV2,
+                "eleven_flash_v2_5" => Model::ElevenFlashV2Dot5,
+                "eleven_v3" => Model::ElevenV3,
+                other => Model::Custom(other.to_string()),
+            })
+        }
     }
 
     impl From<Model> for String {
         fn from(model: Model) -> String {
             match model {
-                Model::ElevenMultilingualV2 => "eleven_multilingual_v2".to_string(),
-                Model::ElevenMultilingualV1 => "eleven_multilingual_v1".to_string(),
-                Model::ElevenEnglishV1 => "eleven_monolingual_v1".to_string(),
-                Model::ElevenEnglishV2 => "eleven_english_sts_v2".to_string(),
-                Model::ElevenTurboV2 => "eleven_turbo_v2".to_string(),
-                Model::ElevenMultilingualV2STS => "eleven_multilingual_sts_v2".to_string(),
-                Model::ElevenTurboV2Dot5 => "eleven_turbo_v2_5".to_string(),
+                Model::Custom(model_id) => model_id,
+                model => model.as_str().to_string(),
             }
         }
     }
 
-    #[derive(Clone, Debug)]
-    pub(crate) struct VoiceID(pub(crate) String);
+    impl Serialize for Model {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Model {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let model_id = String::deserialize(deserializer)?;
+            Ok(model_id.parse().unwrap_or_else(|never| match never {}))
+        }
+    }
+
+    /// A language, by its ISO 639-1 code
+    ///
+    /// Codes without a variant of their own, such as regional locales like `"pt-BR"` or
+    /// ISO 639-3 codes, are kept as [Language::Other], so parsing never fails.
+    ///
+    /// # Example
+    /// ```
+    /// use elevenlabs_rs::Language;
+    ///
+    /// assert_eq!(Language::from_iso("VI"), Language::Vietnamese);
+    /// assert_eq!(Language::Hungarian.to_iso(), "hu");
+    /// assert_eq!(Language::from_iso("pt-BR"), Language::Other("pt-BR".to_string()));
+    /// ```
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub enum Language {
+        Afar,
+        Abkhazian,
+        Avestan,
+        Afrikaans,
+        Akan,
+        Amharic,
+        Aragonese,
+        Arabic,
+        Assamese,
+        Avaric,
+        Aymara,
+        Azerbaijani,
+        Bashkir,
+        Belarusian,
+        Bulgarian,
+        Bislama,
+        Bambara,
+        Bengali,
+        Tibetan,
+        Breton,
+        Bosnian,
+        Catalan,
+        Chechen,
+        Chamorro,
+        Corsican,
+        Cree,
+        Czech,
+        ChurchSlavic,
+        Chuvash,
+        Welsh,
+        Danish,
+        German,
+        Divehi,
+        Dzongkha,
+        Ewe,
+        Greek,
+        English,
+        Esperanto,
+        Spanish,
+        Estonian,
+        Basque,
+        Persian,
+        Fulah,
+        Finnish,
+        Fijian,
+        Faroese,
+        French,
+        WesternFrisian,
+        Irish,
+        ScottishGaelic,
+        Galician,
+        Guarani,
+        Gujarati,
+        Manx,
+        Hausa,
+        Hebrew,
+        Hindi,
+        HiriMotu,
+        Croatian,
+        Haitian,
+        Hungarian,
+        Armenian,
+        Herero,
+        Interlingua,
+        Indonesian,
+        Interlingue,
+        Igbo,
+        SichuanYi,
+        Inupiaq,
+        Ido,
+        Icelandic,
+        Italian,
+        Inuktitut,
+        Japanese,
+        Javanese,
+        Georgian,
+        Kongo,
+        Kikuyu,
+        Kuanyama,
+        Kazakh,
+        Kalaallisut,
+        Khmer,
+        Kannada,
+        Korean,
+        Kanuri,
+        Kashmiri,
+        Kurdish,
+        Komi,
+        Cornish,
+        Kyrgyz,
+        Latin,
+        Luxembourgish,
+        Ganda,
+        Limburgish,
+        Lingala,
+        Lao,
+        Lithuanian,
+        LubaKatanga,
+        Latvian,
+        Malagasy,
+        Marshallese,
+        Maori,
+        Macedonian,
+        Malayalam,
+        Mongolian,
+        Marathi,
+        Malay,
+        Maltese,
+        Burmese,
+        Nauru,
+        NorwegianBokmal,
+        NorthNdebele,
+        Nepali,
+        Ndonga,
+        Dutch,
+        NorwegianNynorsk,
+        Norwegian,
+        SouthNdebele,
+        Navajo,
+        Chichewa,
+        Occitan,
+        Ojibwa,
+        Oromo,
+        Oriya,
+        Ossetian,
+        Punjabi,
+        Pali,
+        Polish,
+        Pashto,
+        Portuguese,
+        Quechua,
+        Romansh,
+        Rundi,
+        Romanian,
+        Russian,
+        Kinyarwanda,
+        Sanskrit,
+        Sardinian,
+        Sindhi,
+        NorthernSami,
+        Sango,
+        Sinhala,
+        Slovak,
+        Slovenian,
+        Samoan,
+        Shona,
+        Somali,
+        Albanian,
+        Serbian,
+        Swati,
+        SouthernSotho,
+        Sundanese,
+        Swedish,
+        Swahili,
+        Tamil,
+        Telugu,
+        Tajik,
+        Thai,
+        Tigrinya,
+        Turkmen,
+        Tagalog,
+        Tswana,
+        Tonga,
+        Turkish,
+        Tsonga,
+        Tatar,
+        Twi,
+        Tahitian,
+        Uyghur,
+        Ukrainian,
+        Urdu,
+        Uzbek,
+        Venda,
+        Vietnamese,
+        Volapuk,
+        Walloon,
+        Wolof,
+        Xhosa,
+        Yiddish,
+        Yoruba,
+        Zhuang,
+        Chinese,
+        Zulu,
+        /// Any other language code
+        Other(String),
+    }
+
+    impl Language {
+        /// The language with the ISO 639-1 code `code`, ignoring case
+        pub fn from_iso(code: &str) -> Self {
+            match code.to_ascii_lowercase().as_str() {
+                "aa" => Language::Afar,
+                "ab" => Language::Abkhazian,
+                "ae" => Language::Avestan,
+                "af" => Language::Afrikaans,
+                "ak" => Language::Akan,
+                "am" => Language::Amharic,
+                "an" => Language::Aragonese,
+                "ar" => Language::Arabic,
+                "as" => Language::Assamese,
+                "av" => Language::Avaric,
+                "ay" => Language::Aymara,
+                "az" => Language::Azerbaijani,
+                "ba" => Language::Bashkir,
+                "be" => Language::Belarusian,
+                "bg" => Language::Bulgarian,
+                "bi" => Language::Bislama,
+                "bm" => Language::Bambara,
+                "bn" => Language::Bengali,
+                "bo" => Language::Tibetan,
+                "br" => Language::Breton,
+                "bs" => Language::Bosnian,
+                "ca" => Language::Catalan,
+                "ce" => Language::Chechen,
+                "ch" => Language::Chamorro,
+                "co" => Language::Corsican,
+                "cr" => Language::Cree,
+                "cs" => Language::Czech,
+                "cu" => Language::ChurchSlavic,
+                "cv" => Language::Chuvash,
+                "cy" => Language::Welsh,
+                "da" => Language::Danish,
+                "de" => Language::German,
+                "dv" => Language::Divehi,
+                "dz" => Language::Dzongkha,
+                "ee" => Language::Ewe,
+                "el" => Language::Greek,
+                "en" => Language::English,
+                "eo" => Language::Esperanto,
+                "es" => Language::Spanish,
+                "et" => Language::Estonian,
+                "eu" => Language::Basque,
+                "fa" => Language::Persian,
+                "ff" => Language::Fulah,
+                "fi" => Language::Finnish,
+                "fj" => Language::Fijian,
+                "fo" => Language::Faroese,
+                "fr" => Language::French,
+                "fy" => Language::WesternFrisian,
+                "ga" => Language::Irish,
+                "gd" => Language::ScottishGaelic,
+                "gl" => Language::Galician,
+                "gn" => Language::Guarani,
+                "gu" => Language::Gujarati,
+                "gv" => Language::Manx,
+                "ha" => Language::Hausa,
+                "he" => Language::Hebrew,
+                "hi" => Language::Hindi,
+                "ho" => Language::HiriMotu,
+                "hr" => Language::Croatian,
+                "ht" => Language::Haitian,
+                "hu" => Language::Hungarian,
+                "hy" => Language::Armenian,
+                "hz" => Language::Herero,
+                "ia" => Language::Interlingua,
+                "id" => Language::Indonesian,
+                "ie" => Language::Interlingue,
+                "ig" => Language::Igbo,
+                "ii" => Language::SichuanYi,
+                "ik" => Language::Inupiaq,
+                "io" => Language::Ido,
+                "is" => Language::Icelandic,
+                "it" => Language::Italian,
+                "iu" => Language::Inuktitut,
+                "ja" => Language::Japanese,
+                "jv" => Language::Javanese,
+                "ka" => Language::Georgian,
+                "kg" => Language::Kongo,
+                "ki" => Language::Kikuyu,
+                "kj" => Language::Kuanyama,
+                "kk" => Language::Kazakh,
+                "kl" => Language::Kalaallisut,
+                "km" => Language::Khmer,
+                "kn" => Language::Kannada,
+                "ko" => Language::Korean,
+                "kr" => Language::Kanuri,
+                "ks" => Language::Kashmiri,
+                "ku" => Language::Kurdish,
+                "kv" => Language::Komi,
+                "kw" => Language::Cornish,
+                "ky" => Language::Kyrgyz,
+                "la" => Language::Latin,
+                "lb" => Language::Luxembourgish,
+                "lg" => Language::Ganda,
+                "li" => Language::Limburgish,
+                "ln" => Language::Lingala,
+                "lo" => Language::Lao,
+                "lt" => Language::Lithuanian,
+                "lu" => Language::LubaKatanga,
+                "lv" => Language::Latvian,
+                "mg" => Language::Malagasy,
+                "mh" => Language::Marshallese,
+                "mi" => Language::Maori,
+                "mk" => Language::Macedonian,
+                "ml" => Language::Malayalam,
+                "mn" => Language::Mongolian,
+                "mr" => Language::Marathi,
+                "ms" => Language::Malay,
+                "mt" => Language::Maltese,
+                "my" => Language::Burmese,
+                "na" => Language::Nauru,
+                "nb" => Language::NorwegianBokmal,
+                "nd" => Language::NorthNdebele,
+                "ne" => Language::Nepali,
+                "ng" => Language::Ndonga,
+                "nl" => Language::Dutch,
+                "nn" => Language::NorwegianNynorsk,
+                "no" => Language::Norwegian,
+                "nr" => Language::SouthNdebele,
+                "nv" => Language::Navajo,
+                "ny" => Language::Chichewa,
+                "oc" => Language::Occitan,
+                "oj" => Language::Ojibwa,
+                "om" => Language::Oromo,
+                "or" => Language::Oriya,
+                "os" => Language::Ossetian,
+                "pa" => Language::Punjabi,
+                "pi" => Language::Pali,
+                "pl" => Language::Polish,
+                "ps" => Language::Pashto,
+                "pt" => Language::Portuguese,
+                "qu" => Language::Quechua,
+                "rm" => Language::Romansh,
+                "rn" => Language::Rundi,
+                "ro" => Language::Romanian,
+                "ru" => Language::Russian,
+                "rw" => Language::Kinyarwanda,
+                "sa" => Language::Sanskrit,
+                "sc" => Language::Sardinian,
+                "sd" => Language::Sindhi,
+                "se" => Language::NorthernSami,
+                "sg" => Language::Sango,
+                "si" => Language::Sinhala,
+                "sk" => Language::Slovak,
+                "sl" => Language::Slovenian,
+                "sm" => Language::Samoan,
+                "sn" => Language::Shona,
+                "so" => Language::Somali,
+                "sq" => Language::Albanian,
+                "sr" => Language::Serbian,
+                "ss" => Language::Swati,
+                "st" => Language::SouthernSotho,
+                "su" => Language::Sundanese,
+                "sv" => Language::Swedish,
+                "sw" => Language::Swahili,
+                "ta" => Language::Tamil,
+                "te" => Language::Telugu,
+                "tg" => Language::Tajik,
+                "th" => Language::Thai,
+                "ti" => Language::Tigrinya,
+                "tk" => Language::Turkmen,
+                "tl" => Language::Tagalog,
+                "tn" => Language::Tswana,
+                "to" => Language::Tonga,
+                "tr" => Language::Turkish,
+                "ts" => Language::Tsonga,
+                "tt" => Language::Tatar,
+                "tw" => Language::Twi,
+                "ty" => Language::Tahitian,
+                "ug" => Language::Uyghur,
+                "uk" => Language::Ukrainian,
+                "ur" => Language::Urdu,
+                "uz" => Language::Uzbek,
+                "ve" => Language::Venda,
+                "vi" => Language::Vietnamese,
+                "vo" => Language::Volapuk,
+                "wa" => Language::Walloon,
+                "wo" => Language::Wolof,
+                "xh" => Language::Xhosa,
+                "yi" => Language::Yiddish,
+                "yo" => Language::Yoruba,
+                "za" => Language::Zhuang,
+                "zh" => Language::Chinese,
+                "zu" => Language::Zulu,
+                _ => Language::Other(code.to_string()),
+            }
+        }
+        /// The language's ISO 639-1 code, or the code it was parsed from for [Language::Other]
+        pub fn to_iso(&self) -> &str {
+            match self {
+                Language::Afar => "aa",
+                Language::Abkhazian => "ab",
+                Language::Avestan => "ae",
+                Language::Afrikaans => "af",
+                Language::Akan => "ak",
+                Language::Amharic => "am",
+                Language::Aragonese => "an",
+                Language::Arabic => "ar",
+                Language::Assamese => "as",
+                Language::Avaric => "av",
+                Language::Aymara => "ay",
+                Language::Azerbaijani => "az",
+                Language::Bashkir => "ba",
+                Language::Belarusian => "be",
+                Language::Bulgarian => "bg",
+                Language::Bislama => "bi",
+                Language::Bambara => "bm",
+                Language::Bengali => "bn",
+                Language::Tibetan => "bo",
+                Language::Breton => "br",
+                Language::Bosnian => "bs",
+                Language::Catalan => "ca",
+                Language::Chechen => "ce",
+                Language::Chamorro => "ch",
+                Language::Corsican => "co",
+                Language::Cree => "cr",
+                Language::Czech => "cs",
+                Language::ChurchSlavic => "cu",
+                Language::Chuvash => "cv",
+                Language::Welsh => "cy",
+                Language::Danish => "da",
+                Language::German => "de",
+                Language::Divehi => "dv",
+                Language::Dzongkha => "dz",
+                Language::Ewe => "ee",
+                Language::Greek => "el",
+                Language::English => "en",
+                Language::Esperanto => "eo",
+                Language::Spanish => "es",
+                Language::Estonian => "et",
+                Language::Basque => "eu",
+                Language::Persian => "fa",
+                Language::Fulah => "ff",
+                Language::Finnish => "fi",
+                Language::Fijian => "fj",
+                Language::Faroese => "fo",
+                Language::French => "fr",
+                Language::WesternFrisian => "fy",
+                Language::Irish => "ga",
+                Language::ScottishGaelic => "gd",
+                Language::Galician => "gl",
+                Language::Guarani => "gn",
+                Language::Gujarati => "gu",
+                Language::Manx => "gv",
+                Language::Hausa => "ha",
+                Language::Hebrew => "he",
+                Language::Hindi => "hi",
+                Language::HiriMotu => "ho",
+                Language::Croatian => "hr",
+                Language::Haitian => "ht",
+                Language::Hungarian => "hu",
+                Language::Armenian => "hy",
+                Language::Herero => "hz",
+                Language::Interlingua => "ia",
+                Language::Indonesian => "id",
+                Language::Interlingue => "ie",
+                Language::Igbo => "ig",
+                Language::SichuanYi => "ii",
+                Language::Inupiaq => "ik",
+                Language::Ido => "io",
+                Language::Icelandic => "is",
+                Language::Italian => "it",
+                Language::Inuktitut => "iu",
+                Language::Japanese => "ja",
+                Language::Javanese => "jv",
+                Language::Georgian => "ka",
+                Language::Kongo => "kg",
+                Language::Kikuyu => "ki",
+                Language::Kuanyama => "kj",
+                Language::Kazakh => "kk",
+                Language::Kalaallisut => "kl",
+                Language::Khmer => "km",
+                Language::Kannada => "kn",
+                Language::Korean => "ko",
+                Language::Kanuri => "kr",
+                Language::Kashmiri => "ks",
+                Language::Kurdish => "ku",
+                Language::Komi => "kv",
+                Language::Cornish => "kw",
+                Language::Kyrgyz => "ky",
+                Language::Latin => "la",
+                Language::Luxembourgish => "lb",
+                Language::Ganda => "lg",
+                Language::Limburgish => "li",
+                Language::Lingala => "ln",
+                Language::Lao => "lo",
+                Language::Lithuanian => "lt",
+                Language::LubaKatanga => "lu",
+                Language::Latvian => "lv",
+                Language::Malagasy => "mg",
+                Language::Marshallese => "mh",
+                Language::Maori => "mi",
+                Language::Macedonian => "mk",
+                Language::Malayalam => "ml",
+                Language::Mongolian => "mn",
+                Language::Marathi => "mr",
+                Language::Malay => "ms",
+                Language::Maltese => "mt",
+                Language::Burmese => "my",
+                Language::Nauru => "na",
+                Language::NorwegianBokmal => "nb",
+                Language::NorthNdebele => "nd",
+                Language::Nepali => "ne",
+                Language::Ndonga => "ng",
+                Language::Dutch => "nl",
+                Language::NorwegianNynorsk => "nn",
+                Language::Norwegian => "no",
+                Language::SouthNdebele => "nr",
+                Language::Navajo => "nv",
+                Language::Chichewa => "ny",
+                Language::Occitan => "oc",
+                Language::Ojibwa => "oj",
+                Language::Oromo => "om",
+                Language::Oriya => "or",
+                Language::Ossetian => "os",
+                Language::Punjabi => "pa",
+                Language::Pali => "pi",
+                Language::Polish => "pl",
+                Language::Pashto => "ps",
+                Language::Portuguese => "pt",
+                Language::Quechua => "qu",
+                Language::Romansh => "rm",
+                Language::Rundi => "rn",
+                Language::Romanian => "ro",
+                Language::Russian => "ru",
+                Language::Kinyarwanda => "rw",
+                Language::Sanskrit => "sa",
+                Language::Sardinian => "sc",
+                Language::Sindhi => "sd",
+                Language::NorthernSami => "se",
+                Language::Sango => "sg",
+                Language::Sinhala => "si",
+                Language::Slovak => "sk",
+                Language::Slovenian => "sl",
+                Language::Samoan => "sm",
+                Language::Shona => "sn",
+                Language::Somali => "so",
+                Language::Albanian => "sq",
+                Language::Serbian => "sr",
+                Language::Swati => "ss",
+                Language::SouthernSotho => "st",
+                Language::Sundanese => "su",
+                Language::Swedish => "sv",
+                Language::Swahili => "sw",
+                Language::Tamil => "ta",
+                Language::Telugu => "te",
+                Language::Tajik => "tg",
+                Language::Thai => "th",
+                Language::Tigrinya => "ti",
+                Language::Turkmen => "tk",
+                Language::Tagalog => "tl",
+                Language::Tswana => "tn",
+                Language::Tonga => "to",
+                Language::Turkish => "tr",
+                Language::Tsonga => "ts",
+                Language::Tatar => "tt",
+                Language::Twi => "tw",
+                Language::Tahitian => "ty",
+                Language::Uyghur => "ug",
+                Language::Ukrainian => "uk",
+                Language::Urdu => "ur",
+                Language::Uzbek => "uz",
+                Language::Venda => "ve",
+                Language::Vietnamese => "vi",
+                Language::Volapuk => "vo",
+                Language::Walloon => "wa",
+                Language::Wolof => "wo",
+                Language::Xhosa => "xh",
+                Language::Yiddish => "yi",
+                Language::Yoruba => "yo",
+                Language::Zhuang => "za",
+                Language::Chinese => "zh",
+                Language::Zulu => "zu",
+                Language::Other(code) => code,
+            }
+        }
+    }
+
+    impl std::fmt::Display for Language {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.to_iso())
+        }
+    }
+
+    impl std::str::FromStr for Language {
+        type Err = std::convert::Infallible;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Ok(Language::from_iso(s))
+        }
+    }
+
+    impl Serialize for Language {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.to_iso())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Language {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let code = String::deserialize(deserializer)?;
+            Ok(Language::from_iso(&code))
+        }
+    }
+
+    /// A voice ID, e.g. one found by
+    /// [find_voice_by_name](crate::ElevenLabsClient::find_voice_by_name)
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct VoiceID(pub(crate) String);
+
+    impl VoiceID {
+        pub fn as_str(&self) -> &str {
+            &self.0
+        }
+    }
 
     impl From<String> for VoiceID {
         fn from(id: String) -> Self {
@@ -55,6 +755,18 @@ pub mod identifiers {
         }
     }
 
+    impl From<VoiceID> for String {
+        fn from(id: VoiceID) -> String {
+            id.0
+        }
+    }
+
+    impl std::fmt::Display for VoiceID {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(&self.0)
+        }
+    }
+
     #[derive(Clone, Debug, Default)]
     pub enum PreMadeVoiceID {
         Adam,
@@ -164,14 +876,224 @@ pub(crate) mod path_segments {
 }
 
 pub mod response_bodies {
-    use serde::Deserialize;
-    #[derive(Clone, Debug, Deserialize)]
+    use serde::{Deserialize, Serialize};
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
     pub struct StatusResponseBody {
         pub status: String,
     }
 }
 
+pub mod uploads {
+    use crate::client::Result;
+    use crate::error::Error;
+    use async_stream::try_stream;
+    use bytes::Bytes;
+    use reqwest::multipart::Part;
+    use reqwest::Body;
+    use std::path::Path;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncRead, AsyncReadExt};
+
+    const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+    /// A file for multipart endpoints, for when the data isn't on disk
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let bytes = Bytes::from(vec![0u8; 1024]);
+    ///     let from_bytes = FileUpload::new(bytes, "sample.mp3", "audio/mpeg");
+    ///     let file = tokio::fs::File::open("sample.wav").await?;
+    ///     let from_reader = FileUpload::from_async_read(file, "sample.wav", "audio/wav");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[derive(Clone, Debug)]
+    pub struct FileUpload {
+        data: UploadData,
+        file_name: String,
+        mime_type: String,
+    }
+
+    #[derive(Clone, Debug)]
+    enum UploadData {
+        Bytes(Bytes),
+        /// Taken by the first request that sends it
+        Stream(Arc<Mutex<Option<Body>>>),
+    }
+
+    impl FileUpload {
+        pub fn new<B, N, M>(bytes: B, file_name: N, mime_type: M) -> Self
+        where
+            B: Into<Bytes>,
+            N: Into<String>,
+            M: Into<String>,
+        {
+            FileUpload {
+                data: UploadData::Bytes(bytes.into()),
+                file_name: file_name.into(),
+                mime_type: mime_type.into(),
+            }
+        }
+        /// Stream the upload from `reader` as the request is sent, without buffering it.
+        ///
+        /// The reader can only be sent once, so a retried request fails with
+        /// [Error::UploadStreamConsumed].
+        pub fn from_async_read<R, N, M>(reader: R, file_name: N, mime_type: M) -> Self
+        where
+            R: AsyncRead + Send + Unpin + 'static,
+            N: Into<String>,
+            M: Into<String>,
+        {
+            FileUpload {
+                data: UploadData::Stream(Arc::new(Mutex::new(Some(read_body(reader))))),
+                file_name: file_name.into(),
+                mime_type: mime_type.into(),
+            }
+        }
+        /// Read a file from disk, taking the mime type from its extension.
+        ///
+        /// Only the file name is sent, not the directories leading to it. Extensions
+        /// without a known mime type are sent as `application/octet-stream`.
+        pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self> {
+            let path = path.as_ref();
+            let file_name = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .ok_or(Box::new(Error::PathNotValidUTF8))?;
+            let extension = path
+                .extension()
+                .ok_or(Box::new(Error::FileExtensionNotFound))?
+                .to_str()
+                .ok_or(Box::new(Error::FileExtensionNotValidUTF8))?;
+            let bytes = std::fs::read(path)?;
+            Ok(FileUpload::new(bytes, file_name, mime_type(extension)))
+        }
+        /// The contents, unless the upload streams from a reader
+        pub fn bytes(&self) -> Option<&Bytes> {
+            match &self.data {
+                UploadData::Bytes(bytes) => Some(bytes),
+                UploadData::Stream(_) => None,
+            }
+        }
+        pub fn file_name(&self) -> &str {
+            &self.file_name
+        }
+        pub fn mime_type(&self) -> &str {
+            &self.mime_type
+        }
+        pub(crate) fn to_part(&self) -> Result<Part> {
+            let part = match &self.data {
+                UploadData::Bytes(bytes) => Part::stream(bytes.clone()),
+                UploadData::Stream(body) => Part::stream(
+                    body.lock()
+                        .unwrap_or_else(|poisoned| poisoned.into_inner())
+                        .take()
+                        .ok_or(Box::new(Error::UploadStreamConsumed))?,
+                ),
+            };
+            Ok(part
+                .file_name(self.file_name.clone())
+                .mime_str(&self.mime_type)?)
+        }
+    }
+
+    /// A request body that streams `reader` in chunks
+    pub(crate) fn read_body<R: AsyncRead + Send + Unpin + 'static>(reader: R) -> Body {
+        Body::wrap_stream(read_chunks(reader))
+    }
+
+    fn read_chunks<R: AsyncRead + Send + Unpin + 'static>(
+        mut reader: R,
+    ) -> impl futures_util::Stream<Item = std::io::Result<Bytes>> + Send + 'static {
+        try_stream! {
+            let mut buf = vec![0; READ_CHUNK_SIZE];
+            loop {
+                let n = reader.read(&mut buf).await?;
+                if n == 0 {
+                    break;
+                }
+                yield Bytes::copy_from_slice(&buf[..n]);
+            }
+        }
+    }
+
+    /// The mime type of a file with `extension`
+    fn mime_type(extension: &str) -> &'static str {
+        match extension.to_ascii_lowercase().as_str() {
+            "mp3" | "mpga" => "audio/mpeg",
+            "wav" => "audio/wav",
+            "flac" => "audio/flac",
+            "ogg" | "oga" => "audio/ogg",
+            "opus" => "audio/opus",
+            "m4a" => "audio/mp4",
+            "aac" => "audio/aac",
+            "aif" | "aiff" => "audio/aiff",
+            "weba" => "audio/webm",
+            "mp4" | "m4v" => "video/mp4",
+            "mov" => "video/quicktime",
+            "webm" => "video/webm",
+            "mkv" => "video/x-matroska",
+            "avi" => "video/x-msvideo",
+            "pdf" => "application/pdf",
+            "doc" => "application/msword",
+            "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+            "txt" => "text/plain",
+            "html" | "htm" => "text/html",
+            "png" => "image/png",
+            "jpg" | "jpeg" => "image/jpeg",
+            _ => "application/octet-stream",
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn from_path_sends_the_file_name_and_its_mime_type() {
+            let dir = std::env::temp_dir().join("elevenlabs_rs_file_upload");
+            std::fs::create_dir_all(&dir).unwrap();
+            for (name, mime) in [
+                ("voice.MP3", "audio/mpeg"),
+                ("clip.mp4", "video/mp4"),
+                ("consent.pdf", "application/pdf"),
+                ("data.xyz", "application/octet-stream"),
+            ] {
+                let path = dir.join(name);
+                std::fs::write(&path, b"data").unwrap();
+                let upload = FileUpload::from_path(&path).unwrap();
+                assert_eq!(upload.file_name(), name);
+                assert_eq!(upload.mime_type(), mime);
+            }
+        }
+
+        #[test]
+        fn from_async_read_streams_once() {
+            let upload = FileUpload::from_async_read(&b"data"[..], "sample.wav", "audio/wav");
+            assert_eq!(upload.bytes(), None);
+            let retry = upload.clone();
+            assert!(upload.to_part().is_ok());
+            let error = retry.to_part().unwrap_err();
+            assert_eq!(error.to_string(), Error::UploadStreamConsumed.to_string());
+        }
+    }
+}
+
 pub mod query_params {
+    use reqwest::Url;
+
+    /// A `key=value` query pair with the value percent-encoded, for the query builders
+    pub(crate) fn query_pair(key: &str, value: impl std::fmt::Display) -> String {
+        let mut url = Url::parse("http://localhost").unwrap();
+        url.query_pairs_mut().append_pair(key, &value.to_string());
+        url.query().unwrap_or_default().to_string()
+    }
+
     #[derive(Clone, Debug)]
     pub enum Latency {
         /// Default latency
@@ -188,7 +1110,7 @@ pub mod query_params {
     }
 
     /// See Elevenlabs documentation on [supported output formats](https://help.elevenlabs.io/hc/en-us/articles/15754340124305-What-audio-formats-do-you-support).
-    #[derive(Clone, Debug)]
+    #[derive(Clone, Debug, PartialEq, Eq)]
     pub enum OutputFormat {
         Mp3_22050Hz32kbps,
         Mp3_44100Hz32kbps,
@@ -202,7 +1124,8 @@ pub mod query_params {
         MuLaw8000Hz,
     }
     impl OutputFormat {
-        pub(crate) fn to_query(&self) -> &str {
+        /// The format name, e.g. `"mp3_44100_192"`
+        pub fn as_str(&self) -> &str {
             match self {
                 OutputFormat::Pcm16000Hz => "pcm_16000",
                 OutputFormat::Pcm22050Hz => "pcm_22050",
@@ -216,5 +1139,121 @@ pub mod query_params {
                 OutputFormat::MuLaw8000Hz => "ulaw_8000",
             }
         }
+        pub fn codec(&self) -> Codec {
+            match self {
+                OutputFormat::Mp3_22050Hz32kbps
+                | OutputFormat::Mp3_44100Hz32kbps
+                | OutputFormat::Mp3_44100Hz64kbps
+                | OutputFormat::Mp3_44100Hz96kbps
+                | OutputFormat::Mp3_44100Hz192kbps => Codec::Mp3,
+                OutputFormat::Pcm16000Hz
+                | OutputFormat::Pcm22050Hz
+                | OutputFormat::Pcm24000Hz
+                | OutputFormat::Pcm44100Hz => Codec::Pcm,
+                OutputFormat::MuLaw8000Hz => Codec::MuLaw,
+            }
+        }
+        pub fn sample_rate(&self) -> u32 {
+            match self {
+                OutputFormat::MuLaw8000Hz => 8000,
+                OutputFormat::Pcm16000Hz => 16000,
+                OutputFormat::Mp3_22050Hz32kbps | OutputFormat::Pcm22050Hz => 22050,
+                OutputFormat::Pcm24000Hz => 24000,
+                OutputFormat::Mp3_44100Hz32kbps
+                | OutputFormat::Mp3_44100Hz64kbps
+                | OutputFormat::Mp3_44100Hz96kbps
+                | OutputFormat::Mp3_44100Hz192kbps
+                | OutputFormat::Pcm44100Hz => 44100,
+            }
+        }
+        /// The bitrate in kbps of MP3 formats, `None` for uncompressed formats
+        pub fn bitrate(&self) -> Option<u32> {
+            match self {
+                OutputFormat::Mp3_22050Hz32kbps | OutputFormat::Mp3_44100Hz32kbps => Some(32),
+                OutputFormat::Mp3_44100Hz64kbps => Some(64),
+                OutputFormat::Mp3_44100Hz96kbps => Some(96),
+                OutputFormat::Mp3_44100Hz192kbps => Some(192),
+                _ => None,
+            }
+        }
+        /// All output formats are mono
+        pub fn channels(&self) -> u16 {
+            1
+        }
+        /// Whether the audio is raw 16-bit little-endian PCM
+        pub fn is_pcm(&self) -> bool {
+            self.codec() == Codec::Pcm
+        }
+    }
+
+    impl std::fmt::Display for OutputFormat {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+
+    /// Parses a format name such as `"pcm_16000"`
+    ///
+    /// # Example
+    /// ```
+    /// use elevenlabs_rs::{Codec, OutputFormat};
+    ///
+    /// let format: OutputFormat = "pcm_24000".parse().unwrap();
+    /// assert_eq!(format.sample_rate(), 24000);
+    /// assert_eq!(format.codec(), Codec::Pcm);
+    /// assert!("opus_48000_64".parse::<OutputFormat>().is_err());
+    /// ```
+    impl std::str::FromStr for OutputFormat {
+        type Err = crate::error::Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Ok(match s {
+                "pcm_16000" => OutputFormat::Pcm16000Hz,
+                "pcm_22050" => OutputFormat::Pcm22050Hz,
+                "pcm_24000" => OutputFormat::Pcm24000Hz,
+                "pcm_44100" => OutputFormat::Pcm44100Hz,
+                "mp3_22050_32" => OutputFormat::Mp3_22050Hz32kbps,
+                "mp3_44100_32" => OutputFormat::Mp3_44100Hz32kbps,
+                "mp3_44100_64" => OutputFormat::Mp3_44100Hz64kbps,
+                "mp3_44100_96" => OutputFormat::Mp3_44100Hz96kbps,
+                "mp3_44100_192" => OutputFormat::Mp3_44100Hz192kbps,
+                "ulaw_8000" => OutputFormat::MuLaw8000Hz,
+                other => {
+                    return Err(crate::error::Error::OutputFormatNotSupported(
+                        other.to_string(),
+                    ))
+                }
+            })
+        }
+    }
+
+    /// The codec of an [OutputFormat]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Codec {
+        Mp3,
+        /// 16-bit little-endian PCM
+        Pcm,
+        /// G.711 μ-law
+        MuLaw,
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn query_pair_encodes_the_value() {
+            let value = "rock & roll = café 日本";
+            let pair = query_pair("search", value);
+            assert_eq!(
+                pair,
+                "search=rock+%26+roll+%3D+caf%C3%A9+%E6%97%A5%E6%9C%AC"
+            );
+            let url = Url::parse(&format!("http://localhost/?{pair}")).unwrap();
+            assert_eq!(
+                url.query_pairs().collect::<Vec<_>>(),
+                [("search".into(), value.into())]
+            );
+        }
     }
 }
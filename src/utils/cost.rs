@@ -0,0 +1,115 @@
+//! Character and credit cost estimation
+//!
+//! Estimates what a request will cost before it is sent, so a batch job can be checked
+//! against the remaining quota of the [Subscription]. The estimate uses the published
+//! per-character pricing and is not a guarantee of what will be billed.
+//!
+//! # Example
+//! ```no_run
+//! use elevenlabs_rs::*;
+//! use elevenlabs_rs::utils::cost::CostEstimate;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<()> {
+//!     let c = ElevenLabsClient::default()?;
+//!     let chapters = vec!["Chapter one...", "Chapter two..."];
+//!     let endpoints = chapters
+//!         .iter()
+//!         .map(|text| {
+//!             let body = TextToSpeechBody::new(text, Model::ElevenFlashV2Dot5);
+//!             TextToSpeech::new(PreMadeVoiceID::Sarah, body)
+//!         })
+//!         .collect::<Vec<_>>();
+//!     let estimate: CostEstimate = endpoints.iter().map(|e| c.estimate_cost(e)).sum();
+//!
+//!     let subscription = c.hit(GetUserSubscriptionInfo).await?;
+//!     if !estimate.fits_within(&subscription) {
+//!         return Err("not enough quota for this job".into());
+//!     }
+//!     for endpoint in endpoints {
+//!         let speech = c.hit(endpoint).await?;
+//!     }
+//!     Ok(())
+//! }
+//! ```
+use crate::endpoints::user::Subscription;
+use crate::shared::identifiers::Model;
+
+/// The model the API uses when a request does not name one
+const API_DEFAULT_MODEL: Model = Model::ElevenMultilingualV2;
+
+/// Requests billed by the character
+pub trait Billable {
+    /// The number of characters the request bills for
+    fn estimated_characters(&self) -> u64;
+    /// The model the request will use, `None` for the API's default
+    fn model_id(&self) -> Option<&str>;
+}
+
+/// The estimated cost of a request, see
+/// [ElevenLabsClient::estimate_cost](crate::ElevenLabsClient::estimate_cost)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CostEstimate {
+    characters: u64,
+    credits: u64,
+}
+
+impl CostEstimate {
+    pub fn new(characters: u64, model: &Model) -> Self {
+        CostEstimate {
+            characters,
+            credits: (characters as f64 * credits_per_character(model)).ceil() as u64,
+        }
+    }
+    pub fn characters(&self) -> u64 {
+        self.characters
+    }
+    /// Credits deducted from the subscription's character quota
+    pub fn credits(&self) -> u64 {
+        self.credits
+    }
+    /// Whether the subscription has enough quota left for this request
+    pub fn fits_within(&self, subscription: &Subscription) -> bool {
+        self.credits as i64 <= subscription.remaining_characters()
+    }
+}
+
+impl std::ops::Add for CostEstimate {
+    type Output = CostEstimate;
+
+    fn add(self, other: CostEstimate) -> CostEstimate {
+        CostEstimate {
+            characters: self.characters + other.characters,
+            credits: self.credits + other.credits,
+        }
+    }
+}
+
+impl std::iter::Sum for CostEstimate {
+    fn sum<I: Iterator<Item = CostEstimate>>(iter: I) -> CostEstimate {
+        iter.fold(CostEstimate::default(), |total, estimate| total + estimate)
+    }
+}
+
+/// Credits per character for a model.
+///
+/// Flash and Turbo models cost half a credit per character. Other models, including
+/// [Model::Custom] IDs, cost one.
+pub fn credits_per_character(model: &Model) -> f64 {
+    match model {
+        Model::ElevenFlashV2
+        | Model::ElevenFlashV2Dot5
+        | Model::ElevenTurboV2
+        | Model::ElevenTurboV2Dot5 => 0.5,
+        _ => 1.0,
+    }
+}
+
+/// Estimate the cost of `request`, using `default_model` when the request has no model
+pub fn estimate<T: Billable + ?Sized>(request: &T, default_model: Option<&Model>) -> CostEstimate {
+    let model = match request.model_id() {
+        Some(model_id) => model_id.parse().unwrap_or_else(|never| match never {}),
+        None => default_model.cloned().unwrap_or(API_DEFAULT_MODEL),
+    };
+    CostEstimate::new(request.estimated_characters(), &model)
+}
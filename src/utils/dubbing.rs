@@ -0,0 +1,192 @@
+//! Waiting for dubbing projects to finish
+use crate::client::{ElevenLabsClient, Result};
+use crate::endpoints::dubbing::{GetDubbingProjectMetadata, GetDubbingProjectMetadataResponse};
+use crate::error::Error;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::UnboundedSender;
+
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+const DEFAULT_MAX_INTERVAL: Duration = Duration::from_secs(60);
+const DEFAULT_BACKOFF: f64 = 1.5;
+/// The shortest wait between polls, whatever the interval is set to
+const MIN_INTERVAL: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: f64 = 10.0;
+/// Statuses after which a project's status no longer changes
+const FINAL_STATUSES: [&str; 2] = ["dubbed", "failed"];
+
+/// Poll a dubbing project until it is dubbed or has failed, with the default settings of
+/// [DubbingWatcher]
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::watch_dubbing;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let dub = DubAVideoOrAnAudioFile::from_file("interview.mp4", "en", "es");
+///     let resp = c.hit(dub).await?;
+///     let project = watch_dubbing(&c, resp.dubbing_id()).await?;
+///     if let Some(error) = project.error() {
+///         return Err(error.into());
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn watch_dubbing(
+    client: &ElevenLabsClient,
+    dubbing_id: &str,
+) -> Result<GetDubbingProjectMetadataResponse> {
+    DubbingWatcher::new(client, dubbing_id).watch().await
+}
+
+/// Polls a dubbing project until it is dubbed or has failed
+///
+/// The wait between polls starts at the interval and grows by the backoff factor after
+/// each poll, up to the maximum interval.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::DubbingWatcher;
+/// use std::time::Duration;
+/// use tokio::sync::mpsc::unbounded_channel;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let (tx, mut rx) = unbounded_channel::<GetDubbingProjectMetadataResponse>();
+///     tokio::spawn(async move {
+///         while let Some(project) = rx.recv().await {
+///             println!("{}: {}", project.name(), project.status());
+///         }
+///     });
+///     let project = DubbingWatcher::new(&c, "dubbing_id")
+///         .with_interval(Duration::from_secs(10))
+///         .with_timeout(Duration::from_secs(60 * 60))
+///         .with_progress(tx)
+///         .watch()
+///         .await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct DubbingWatcher<'a> {
+    client: &'a ElevenLabsClient,
+    dubbing_id: String,
+    interval: Duration,
+    max_interval: Duration,
+    backoff: f64,
+    timeout: Option<Duration>,
+    progress: Option<UnboundedSender<GetDubbingProjectMetadataResponse>>,
+}
+
+impl<'a> DubbingWatcher<'a> {
+    pub fn new(client: &'a ElevenLabsClient, dubbing_id: &str) -> Self {
+        DubbingWatcher {
+            client,
+            dubbing_id: dubbing_id.to_string(),
+            interval: DEFAULT_INTERVAL,
+            max_interval: DEFAULT_MAX_INTERVAL,
+            backoff: DEFAULT_BACKOFF,
+            timeout: None,
+            progress: None,
+        }
+    }
+    /// The wait before the second poll, 5 seconds by default and at least half a second
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval.max(MIN_INTERVAL);
+        self
+    }
+    /// The longest wait between polls, 60 seconds by default and at least half a second
+    pub fn with_max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval.max(MIN_INTERVAL);
+        self
+    }
+    /// The factor the wait grows by after each poll, 1.5 by default, between 1.0 and 10.0.
+    /// Use 1.0 for a fixed interval.
+    pub fn with_backoff(mut self, backoff: f64) -> Self {
+        self.backoff = if backoff.is_nan() {
+            DEFAULT_BACKOFF
+        } else {
+            backoff.clamp(1.0, MAX_BACKOFF)
+        };
+        self
+    }
+    /// Give up with [Error::DubbingTimeout] if the project is still dubbing after `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+    /// Send the project's metadata on `progress` after every poll, including the last
+    pub fn with_progress(
+        mut self,
+        progress: UnboundedSender<GetDubbingProjectMetadataResponse>,
+    ) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Poll until the project is dubbed or has failed and return its final metadata.
+    ///
+    /// A failed project is returned, not an error; its [error](GetDubbingProjectMetadataResponse::error)
+    /// holds the reason.
+    pub async fn watch(self) -> Result<GetDubbingProjectMetadataResponse> {
+        let started = Instant::now();
+        let mut interval = self.interval;
+        loop {
+            let endpoint = GetDubbingProjectMetadata::new(self.dubbing_id.clone().into());
+            let project = self.client.hit(endpoint).await?;
+            let done = FINAL_STATUSES.contains(&project.status());
+            if let Some(progress) = &self.progress {
+                // A dropped receiver only means nobody is listening any more
+                let _ = progress.send(project.clone());
+            }
+            if done {
+                return Ok(project);
+            }
+            if let Some(timeout) = self.timeout {
+                let elapsed = started.elapsed();
+                if elapsed >= timeout {
+                    return Err(Box::new(Error::DubbingTimeout(self.dubbing_id)));
+                }
+                interval = interval.min(timeout - elapsed);
+            }
+            tokio::time::sleep(interval).await;
+            interval = self.next_interval(interval);
+        }
+    }
+
+    fn next_interval(&self, interval: Duration) -> Duration {
+        Duration::try_from_secs_f64(interval.as_secs_f64() * self.backoff)
+            .unwrap_or(self.max_interval)
+            .clamp(MIN_INTERVAL, self.max_interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_is_bounded() {
+        let client = ElevenLabsClient::new("key");
+        for backoff in [f64::INFINITY, f64::MAX, f64::NAN, -1.0] {
+            let watcher = DubbingWatcher::new(&client, "dubbing_id").with_backoff(backoff);
+            let next = watcher.next_interval(watcher.max_interval);
+            assert_eq!(next, DEFAULT_MAX_INTERVAL, "{backoff}");
+        }
+    }
+
+    #[test]
+    fn interval_has_a_floor() {
+        let client = ElevenLabsClient::new("key");
+        let watcher = DubbingWatcher::new(&client, "dubbing_id")
+            .with_interval(Duration::ZERO)
+            .with_max_interval(Duration::ZERO)
+            .with_backoff(1.0);
+        assert_eq!(watcher.interval, MIN_INTERVAL);
+        assert_eq!(watcher.next_interval(Duration::ZERO), MIN_INTERVAL);
+    }
+}
@@ -0,0 +1,88 @@
+//! Dynamic variable placeholder validation
+//!
+//! Agent prompts and first messages can reference dynamic variables as
+//! `{{name}}` placeholders. A missing variable is not rejected up front, so
+//! check the variables you are about to send before starting a conversation.
+//!
+//! # Example
+//! ```
+//! use elevenlabs_rs::utils::dynamic_variables::validate;
+//! use std::collections::HashMap;
+//!
+//! let prompt = "You are helping {{user_name}} with order {{order_id}} at {{system__time}}.";
+//! let first_message = "Hi {{ user_name }}!";
+//!
+//! let mut vars = HashMap::new();
+//! vars.insert("user_name".to_string(), "Ada".to_string());
+//! vars.insert("locale".to_string(), "en-GB".to_string());
+//!
+//! let report = validate(&[prompt, first_message], &vars);
+//! assert!(!report.is_valid());
+//! assert_eq!(report.missing(), &["order_id".to_string()]);
+//! assert_eq!(report.extra(), &["locale".to_string()]);
+//! ```
+use std::collections::{BTreeSet, HashMap};
+
+/// Prefix of the variables the platform fills in itself, e.g. `system__time`
+const SYSTEM_PREFIX: &str = "system__";
+
+/// The outcome of checking dynamic variables against placeholders
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DynamicVariablesReport {
+    missing: Vec<String>,
+    extra: Vec<String>,
+}
+
+impl DynamicVariablesReport {
+    /// Placeholders that have no variable, sorted by name
+    pub fn missing(&self) -> &[String] {
+        &self.missing
+    }
+    /// Variables that no placeholder references, sorted by name
+    pub fn extra(&self) -> &[String] {
+        &self.extra
+    }
+    /// `true` when every placeholder has a variable.
+    ///
+    /// Extra variables are harmless and do not make a report invalid.
+    pub fn is_valid(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+/// Collect the `{{placeholder}}` names in `text`, ignoring surrounding whitespace
+pub fn placeholders(text: &str) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        rest = &rest[start + 2..];
+        let Some(end) = rest.find("}}") else {
+            break;
+        };
+        let name = rest[..end].trim();
+        if !name.is_empty() {
+            names.insert(name.to_string());
+        }
+        rest = &rest[end + 2..];
+    }
+    names
+}
+
+/// Check that `variables` covers every placeholder in `texts`
+///
+/// `system__` placeholders are provided by the platform and never reported missing.
+pub fn validate<V>(texts: &[&str], variables: &HashMap<String, V>) -> DynamicVariablesReport {
+    let referenced = texts
+        .iter()
+        .flat_map(|text| placeholders(text))
+        .collect::<BTreeSet<String>>();
+    let provided = variables.keys().cloned().collect::<BTreeSet<String>>();
+    DynamicVariablesReport {
+        missing: referenced
+            .difference(&provided)
+            .filter(|name| !name.starts_with(SYSTEM_PREFIX))
+            .cloned()
+            .collect(),
+        extra: provided.difference(&referenced).cloned().collect(),
+    }
+}
@@ -0,0 +1,173 @@
+//! Bulk history cleanup
+use crate::client::{ElevenLabsClient, Result};
+use crate::endpoints::history::{
+    DeleteHistoryItem, GetGeneratedItems, HistoryQuery, HistorySource,
+};
+use futures_util::{stream, StreamExt};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const PAGE_SIZE: u16 = 1000;
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Which history items [purge_history] deletes
+///
+/// An empty filter matches every item.
+#[derive(Clone, Debug, Default)]
+pub struct HistoryFilter {
+    voice_id: Option<String>,
+    model_id: Option<String>,
+    source: Option<HistorySource>,
+    older_than: Option<Duration>,
+    concurrency: Option<usize>,
+    dry_run: bool,
+}
+
+impl HistoryFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_voice_id<T: Into<String>>(mut self, voice_id: T) -> Self {
+        self.voice_id = Some(voice_id.into());
+        self
+    }
+    pub fn with_model_id<T: Into<String>>(mut self, model_id: T) -> Self {
+        self.model_id = Some(model_id.into());
+        self
+    }
+    pub fn with_source(mut self, source: HistorySource) -> Self {
+        self.source = Some(source);
+        self
+    }
+    /// Only items generated more than `age` ago
+    pub fn with_older_than(mut self, age: Duration) -> Self {
+        self.older_than = Some(age);
+        self
+    }
+    /// How many deletes run at once, 4 by default
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = Some(concurrency.max(1));
+        self
+    }
+    /// Count the matching items without deleting them
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    fn to_query(&self, cutoff: Option<u64>) -> HistoryQuery {
+        let mut query = HistoryQuery::default().with_page_size(PAGE_SIZE);
+        if let Some(voice_id) = &self.voice_id {
+            query = query.with_voice_id(voice_id.as_str());
+        }
+        if let Some(model_id) = &self.model_id {
+            query = query.with_model_id(model_id.as_str());
+        }
+        if let Some(source) = &self.source {
+            query = query.with_source(source.clone());
+        }
+        if let Some(cutoff) = cutoff {
+            query = query.with_date_before_unix(cutoff);
+        }
+        query
+    }
+}
+
+/// The outcome of [purge_history]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PurgeReport {
+    matched: usize,
+    deleted: usize,
+    failed: Vec<(String, String)>,
+}
+
+impl PurgeReport {
+    /// Items that matched the filter
+    pub fn matched(&self) -> usize {
+        self.matched
+    }
+    /// Items deleted, always 0 for a dry run
+    pub fn deleted(&self) -> usize {
+        self.deleted
+    }
+    /// The history item IDs that could not be deleted, with the error
+    pub fn failed(&self) -> &[(String, String)] {
+        &self.failed
+    }
+}
+
+/// Delete every history item that matches `filter`
+///
+/// All matching items are listed before any is deleted, so deleting does not disturb
+/// paging. A failed delete is recorded in the report and the purge carries on.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::{purge_history, HistoryFilter};
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let filter = HistoryFilter::new()
+///         .with_voice_id("some_voice_id")
+///         .with_older_than(Duration::from_secs(30 * 24 * 60 * 60))
+///         .with_dry_run(true);
+///     let report = purge_history(&c, filter).await?;
+///     println!("{} items would be deleted", report.matched());
+///     Ok(())
+/// }
+/// ```
+pub async fn purge_history(
+    client: &ElevenLabsClient,
+    filter: HistoryFilter,
+) -> Result<PurgeReport> {
+    let cutoff = match filter.older_than {
+        Some(age) => {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+            Some(now.saturating_sub(age).as_secs())
+        }
+        None => None,
+    };
+
+    let mut ids = Vec::new();
+    let mut query = filter.to_query(cutoff);
+    loop {
+        let page = client.hit(GetGeneratedItems::new(query)).await?;
+        ids.extend(
+            page.history()
+                .iter()
+                .filter(|item| cutoff.is_none_or(|cutoff| item.date_unix() < cutoff))
+                .map(|item| item.history_item_id().to_string()),
+        );
+        if !page.has_more() {
+            break;
+        }
+        query = filter
+            .to_query(cutoff)
+            .with_start_after_history_item_id(page.last_history_item_id());
+    }
+
+    let mut report = PurgeReport {
+        matched: ids.len(),
+        ..PurgeReport::default()
+    };
+    if filter.dry_run {
+        return Ok(report);
+    }
+
+    let concurrency = filter.concurrency.unwrap_or(DEFAULT_CONCURRENCY);
+    let mut deletes = stream::iter(ids)
+        .map(|id| async move {
+            let result = client.hit(DeleteHistoryItem::new(id.as_str())).await;
+            (id, result)
+        })
+        .buffer_unordered(concurrency);
+    while let Some((id, result)) = deletes.next().await {
+        match result {
+            Ok(_) => report.deleted += 1,
+            Err(error) => report.failed.push((id, error.to_string())),
+        }
+    }
+    Ok(report)
+}
@@ -7,9 +7,23 @@ use std::{fs::File, io::prelude::*};
 
 #[cfg(feature = "playback")]
 mod playback;
+pub mod cost;
+mod dubbing;
+pub mod dynamic_variables;
+mod history;
+mod output;
+pub mod quota;
+mod scoped;
+pub mod transcode;
+mod zip;
 
 #[cfg(feature = "playback")]
-pub use playback::{play, stream_audio};
+pub use playback::{play, play_with_format, stream_audio, stream_audio_with_format, RodioOutput};
+pub use dubbing::{watch_dubbing, DubbingWatcher};
+pub use history::{purge_history, HistoryFilter, PurgeReport};
+pub use output::{stream_to, AudioOutput};
+pub use scoped::ScopedVoice;
+pub use zip::unzip;
 
 /// Save audio to a file
 pub fn save(filename: &str, data: Bytes) -> Result<()> {
@@ -0,0 +1,77 @@
+//! Pluggable audio output
+use crate::client::Result;
+use crate::shared::query_params::{Codec, OutputFormat};
+use bytes::{BufMut, Bytes, BytesMut};
+use futures_util::{pin_mut, Stream, StreamExt};
+
+/// Somewhere to play audio, e.g. a game engine's mixer or a device the crate doesn't
+/// support
+///
+/// With the `playback` feature, [RodioOutput](crate::utils::RodioOutput) plays to the
+/// default output device.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::{stream_to, AudioOutput};
+///
+/// /// Hands audio to the host application's mixer
+/// struct Mixer(std::sync::mpsc::Sender<Option<Bytes>>);
+///
+/// impl AudioOutput for Mixer {
+///     fn play(&mut self, audio: Bytes, _format: &OutputFormat) -> Result<()> {
+///         Ok(self.0.send(Some(audio))?)
+///     }
+///     fn stop(&mut self) {
+///         let _ = self.0.send(None);
+///     }
+///     fn clear(&mut self) {
+///         let _ = self.0.send(None);
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let (tx, rx) = std::sync::mpsc::channel();
+///     let mut mixer = Mixer(tx);
+///     let c = ElevenLabsClient::default()?;
+///     let format = OutputFormat::Pcm24000Hz;
+///     let body = TextToSpeechBody::new("Streamed to the mixer", Model::ElevenFlashV2Dot5);
+///     let endpoint = TextToSpeechStream::new(PreMadeVoiceID::Sarah, body)
+///         .with_query(SpeechQuery::default().with_output_format(format.clone()));
+///     stream_to(&mut mixer, c.hit(endpoint).await?, &format).await?;
+///     Ok(())
+/// }
+/// ```
+pub trait AudioOutput {
+    /// Queue `audio` to play after the audio already queued
+    fn play(&mut self, audio: Bytes, format: &OutputFormat) -> Result<()>;
+    /// Stop playing and drop the queued audio
+    fn stop(&mut self);
+    /// Drop the queued audio, keeping the output ready to play more
+    fn clear(&mut self);
+}
+
+/// Play a stream of audio in `format` on `output`, chunk by chunk as it arrives.
+///
+/// PCM chunks are only handed over as whole samples, the odd byte of a chunk that ends
+/// halfway through a sample is kept for the next one.
+pub async fn stream_to<O: AudioOutput + ?Sized>(
+    output: &mut O,
+    data: impl Stream<Item = Result<Bytes>>,
+    format: &OutputFormat,
+) -> Result<()> {
+    pin_mut!(data);
+    let mut pending = BytesMut::new();
+    while let Some(chunk) = data.next().await {
+        pending.put(chunk?);
+        let whole = match format.codec() {
+            Codec::Pcm => pending.len() - pending.len() % 2,
+            _ => pending.len(),
+        };
+        if whole > 0 {
+            output.play(pending.split_to(whole).freeze(), format)?;
+        }
+    }
+    Ok(())
+}
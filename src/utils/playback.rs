@@ -1,10 +1,19 @@
 use crate::client::Result;
+use crate::shared::query_params::{Codec, OutputFormat};
+use crate::utils::output::AudioOutput;
+use crate::utils::transcode::{pcm_bytes_to_samples, ulaw_to_linear};
 use bytes::Bytes;
 use bytes::{BufMut, BytesMut};
 use futures_util::{pin_mut, Stream, StreamExt};
-use rodio::{Decoder, OutputStream, Sink};
+use rodio::buffer::SamplesBuffer;
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
+
+type BoxedSource = Box<dyn Source<Item = i16> + Send>;
 
 /// Play audio
+///
+/// The format is detected from the data, which works for MP3 but not for raw PCM or
+/// μ-law, see [play_with_format].
 pub fn play(data: Bytes) -> Result<()> {
     let (_stream, stream_handle) = OutputStream::try_default()?;
     let source = Decoder::new(std::io::Cursor::new(data))?;
@@ -14,6 +23,137 @@ pub fn play(data: Bytes) -> Result<()> {
     Ok(())
 }
 
+/// Play audio in the output format it was requested in
+///
+/// Raw PCM and μ-law have no header to detect the format from, so they are played at
+/// the sample rate of `format`.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::play_with_format;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = TextToSpeechBody::new("Raw and uncompressed", Model::ElevenFlashV2Dot5);
+///     let endpoint = TextToSpeech::new(PreMadeVoiceID::Sarah, body)
+///         .with_query(SpeechQuery::default().with_output_format(OutputFormat::Pcm24000Hz));
+///     let speech = c.hit(endpoint).await?;
+///     play_with_format(speech, &OutputFormat::Pcm24000Hz)?;
+///     Ok(())
+/// }
+/// ```
+pub fn play_with_format(data: Bytes, format: &OutputFormat) -> Result<()> {
+    let (_stream, stream_handle) = OutputStream::try_default()?;
+    let sink = Sink::try_new(&stream_handle)?;
+    sink.append(source(data, format)?);
+    sink.sleep_until_end();
+    Ok(())
+}
+
+/// Like [stream_audio], but for audio in `format`.
+///
+/// PCM and μ-law chunks are played as they arrive, without waiting for a buffer to
+/// fill, as they need no decoding.
+pub async fn stream_audio_with_format(
+    data: impl Stream<Item = Result<Bytes>>,
+    format: &OutputFormat,
+) -> Result<()> {
+    if format.codec() == Codec::Mp3 {
+        return stream_audio(data).await;
+    }
+    pin_mut!(data);
+    let audio_output = OutputStream::try_default()?;
+    let audio_sink = Sink::try_new(&audio_output.1)?;
+    // A PCM chunk may end halfway through a sample
+    let mut pending = BytesMut::new();
+
+    while let Some(resulting_bytes) = data.next().await {
+        pending.put(resulting_bytes?);
+        let whole = match format.codec() {
+            Codec::Pcm => pending.len() - pending.len() % 2,
+            _ => pending.len(),
+        };
+        if whole > 0 {
+            audio_sink.append(source(pending.split_to(whole).freeze(), format)?);
+        }
+    }
+
+    audio_sink.sleep_until_end();
+
+    Ok(())
+}
+
+/// An [AudioOutput] playing to an output device with rodio
+///
+/// Chunks of MP3 are decoded on their own, so a stream of MP3 should be handed over in
+/// chunks that start on a frame boundary, such as the 16KB chunks [stream_audio] uses.
+pub struct RodioOutput {
+    // Playback stops when the stream is dropped
+    _stream: OutputStream,
+    handle: OutputStreamHandle,
+    sink: Sink,
+}
+
+impl RodioOutput {
+    /// Play to the default output device
+    pub fn try_default() -> Result<Self> {
+        let (stream, handle) = OutputStream::try_default()?;
+        let sink = Sink::try_new(&handle)?;
+        Ok(RodioOutput {
+            _stream: stream,
+            handle,
+            sink,
+        })
+    }
+    pub fn handle(&self) -> &OutputStreamHandle {
+        &self.handle
+    }
+    pub fn sink(&self) -> &Sink {
+        &self.sink
+    }
+    /// Block until the queued audio has played
+    pub fn sleep_until_end(&self) {
+        self.sink.sleep_until_end();
+    }
+}
+
+impl AudioOutput for RodioOutput {
+    fn play(&mut self, audio: Bytes, format: &OutputFormat) -> Result<()> {
+        self.sink.append(source(audio, format)?);
+        Ok(())
+    }
+    fn stop(&mut self) {
+        self.sink.stop();
+    }
+    fn clear(&mut self) {
+        // Sink::clear leaves the sink paused
+        self.sink.clear();
+        self.sink.play();
+    }
+}
+
+fn source(data: Bytes, format: &OutputFormat) -> Result<BoxedSource> {
+    let channels = format.channels();
+    let sample_rate = format.sample_rate();
+    Ok(match format.codec() {
+        Codec::Mp3 => Box::new(Decoder::new(std::io::Cursor::new(data))?),
+        Codec::Pcm => Box::new(SamplesBuffer::new(
+            channels,
+            sample_rate,
+            pcm_bytes_to_samples(&data),
+        )),
+        Codec::MuLaw => Box::new(SamplesBuffer::new(
+            channels,
+            sample_rate,
+            data.iter()
+                .map(|&ulaw| ulaw_to_linear(ulaw))
+                .collect::<Vec<_>>(),
+        )),
+    })
+}
+
 // TODO: Improve
 pub async fn stream_audio(data: impl Stream<Item = Result<Bytes>>) -> Result<()> {
     pin_mut!(data);
@@ -0,0 +1,158 @@
+//! A subscription quota guard
+//!
+//! [QuotaGuard] wraps a client, caches the [Subscription] and counts the credits of the
+//! requests it sends, so a request that would exceed the remaining quota is stopped
+//! before it is sent instead of failing halfway through a batch.
+//!
+//! # Example
+//! ```no_run
+//! use elevenlabs_rs::*;
+//! use elevenlabs_rs::utils::quota::QuotaGuard;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<()> {
+//!     let guard = QuotaGuard::new(ElevenLabsClient::default()?);
+//!     for text in ["One.", "Two.", "Three."] {
+//!         let body = TextToSpeechBody::new(text, Model::ElevenFlashV2Dot5);
+//!         let speech = guard.hit(TextToSpeech::new(PreMadeVoiceID::Sarah, body)).await?;
+//!     }
+//!     println!("{:?} credits left", guard.remaining().await?);
+//!     Ok(())
+//! }
+//! ```
+use crate::client::{ElevenLabsClient, Result};
+use crate::endpoints::user::{GetUserSubscriptionInfo, Subscription};
+use crate::endpoints::Endpoint;
+use crate::error::{ElevenLabsClientError, QuotaError};
+use crate::utils::cost::Billable;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+const DEFAULT_MAX_AGE: Duration = Duration::from_secs(300);
+/// Client error statuses after which the cached subscription can't be trusted
+const REFRESH_STATUSES: [&str; 3] = ["quota_exceeded", "invalid_api_key", "unauthorized"];
+
+type WarningHandler = Box<dyn Fn(&QuotaError) + Send + Sync>;
+
+/// Checks requests against the subscription's remaining quota, see [the module docs](self)
+pub struct QuotaGuard {
+    client: ElevenLabsClient,
+    max_age: Duration,
+    on_warning: Option<WarningHandler>,
+    state: Mutex<Option<QuotaState>>,
+}
+
+struct QuotaState {
+    subscription: Subscription,
+    fetched_at: Instant,
+    /// Credits used by requests sent since the subscription was fetched
+    consumed: u64,
+}
+
+impl QuotaState {
+    fn remaining(&self) -> i64 {
+        self.subscription.remaining_characters() - self.consumed as i64
+    }
+}
+
+impl QuotaGuard {
+    pub fn new(client: ElevenLabsClient) -> Self {
+        QuotaGuard {
+            client,
+            max_age: DEFAULT_MAX_AGE,
+            on_warning: None,
+            state: Mutex::new(None),
+        }
+    }
+    /// How long the cached subscription is used before it is fetched again, 5 minutes by
+    /// default
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+    /// Send requests that exceed the quota anyway, calling `handler` first, instead of
+    /// rejecting them
+    pub fn with_warning_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&QuotaError) + Send + Sync + 'static,
+    {
+        self.on_warning = Some(Box::new(handler));
+        self
+    }
+    pub fn client(&self) -> &ElevenLabsClient {
+        &self.client
+    }
+    /// Credits left by the local count, fetching the subscription if the cache is stale
+    pub async fn remaining(&self) -> Result<i64> {
+        let mut state = self.state.lock().await;
+        self.refresh_if_stale(&mut state).await?;
+        Ok(state.as_ref().map(QuotaState::remaining).unwrap_or_default())
+    }
+    /// Drop the cached subscription so the next request fetches it again
+    pub async fn invalidate(&self) {
+        *self.state.lock().await = None;
+    }
+
+    /// Hit `endpoint` if its estimated cost fits in the remaining quota.
+    ///
+    /// Returns [QuotaError::Exceeded] without sending the request when it does not fit,
+    /// unless a warning handler is set.
+    pub async fn hit<T: Endpoint + Billable>(&self, endpoint: T) -> Result<T::ResponseBody> {
+        let credits = self.client.estimate_cost(&endpoint).credits();
+        {
+            let mut state = self.state.lock().await;
+            self.refresh_if_stale(&mut state).await?;
+            if let Some(state) = state.as_mut() {
+                let remaining = state.remaining();
+                if credits as i64 > remaining {
+                    let error = QuotaError::Exceeded {
+                        required: credits,
+                        remaining,
+                    };
+                    match &self.on_warning {
+                        Some(on_warning) => on_warning(&error),
+                        None => return Err(Box::new(error)),
+                    }
+                }
+                // Reserve the credits so concurrent requests see them as used
+                state.consumed += credits;
+            }
+        }
+
+        match self.client.hit(endpoint).await {
+            Ok(body) => Ok(body),
+            Err(error) => {
+                let mut state = self.state.lock().await;
+                if needs_refresh(error.as_ref()) {
+                    *state = None;
+                } else if let Some(state) = state.as_mut() {
+                    state.consumed = state.consumed.saturating_sub(credits);
+                }
+                Err(error)
+            }
+        }
+    }
+
+    async fn refresh_if_stale(&self, state: &mut Option<QuotaState>) -> Result<()> {
+        let stale = match state {
+            Some(state) => state.fetched_at.elapsed() > self.max_age,
+            None => true,
+        };
+        if stale {
+            let subscription = self.client.hit(GetUserSubscriptionInfo).await?;
+            *state = Some(QuotaState {
+                subscription,
+                fetched_at: Instant::now(),
+                consumed: 0,
+            });
+        }
+        Ok(())
+    }
+}
+
+fn needs_refresh(error: &(dyn std::error::Error + Send + Sync + 'static)) -> bool {
+    error
+        .downcast_ref::<ElevenLabsClientError>()
+        .and_then(ElevenLabsClientError::detail)
+        .is_some_and(|detail| REFRESH_STATUSES.contains(&detail.status()))
+}
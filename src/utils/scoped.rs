@@ -0,0 +1,72 @@
+//! Temporary resources for tests against a real workspace
+use crate::client::{ElevenLabsClient, Result};
+use crate::endpoints::voice::{AddVoice, AddVoiceBody, DeleteVoice};
+
+/// A voice that is deleted again when the guard goes away
+///
+/// Call [teardown](ScopedVoice::teardown) at the end of a test to delete the voice and
+/// see any error. A guard dropped without it, e.g. by a failing assertion, deletes the
+/// voice on a background task, which only runs if the Tokio runtime is still alive.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::ScopedVoice;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = AddVoiceBody::new("Test voice", vec!["sample.mp3".to_string()]);
+///     let voice = ScopedVoice::create(&c, body).await?;
+///     let settings = c.hit(GetVoiceSettings::new(voice.voice_id())).await?;
+///     voice.teardown().await?;
+///     Ok(())
+/// }
+/// ```
+pub struct ScopedVoice {
+    client: ElevenLabsClient,
+    voice_id: Option<String>,
+}
+
+impl ScopedVoice {
+    /// Add a voice with `body`
+    pub async fn create(client: &ElevenLabsClient, body: AddVoiceBody) -> Result<Self> {
+        let resp = client.hit(AddVoice::new(body)).await?;
+        Ok(Self::adopt(client, resp.get_voice_id().clone()))
+    }
+    /// Guard a voice that was created some other way
+    pub fn adopt<T: Into<String>>(client: &ElevenLabsClient, voice_id: T) -> Self {
+        ScopedVoice {
+            client: client.clone(),
+            voice_id: Some(voice_id.into()),
+        }
+    }
+    pub fn voice_id(&self) -> &str {
+        self.voice_id.as_deref().unwrap_or_default()
+    }
+    /// Delete the voice now
+    pub async fn teardown(mut self) -> Result<()> {
+        if let Some(voice_id) = self.voice_id.take() {
+            self.client.hit(DeleteVoice::new(voice_id)).await?;
+        }
+        Ok(())
+    }
+    /// Keep the voice, the guard no longer deletes it
+    pub fn into_voice_id(mut self) -> String {
+        self.voice_id.take().unwrap_or_default()
+    }
+}
+
+impl Drop for ScopedVoice {
+    fn drop(&mut self) {
+        let Some(voice_id) = self.voice_id.take() else {
+            return;
+        };
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            let client = self.client.clone();
+            runtime.spawn(async move {
+                let _ = client.hit(DeleteVoice::new(voice_id)).await;
+            });
+        }
+    }
+}
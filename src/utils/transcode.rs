@@ -0,0 +1,414 @@
+//! μ-law/PCM transcoding helpers
+//!
+//! Twilio media streams carry 8kHz μ-law audio, while ElevenLabs can return
+//! 16-bit little-endian PCM at 16kHz and above. These helpers convert between
+//! the two so a media stream bridge can use any PCM output format.
+//!
+//! # Example
+//! ```
+//! use elevenlabs_rs::utils::transcode::{pcm_to_ulaw_8k, ulaw_8k_to_pcm};
+//!
+//! // 20ms of silence as 16kHz PCM
+//! let pcm_16k = vec![0u8; 640];
+//! let ulaw = pcm_to_ulaw_8k(&pcm_16k, 16000);
+//! assert_eq!(ulaw.len(), 160);
+//!
+//! let pcm = ulaw_8k_to_pcm(&ulaw, 16000);
+//! assert_eq!(pcm.len(), 640);
+//! ```
+//!
+//! A media stream bridge that does not know the agent's output format up front can use
+//! a [Transcoder] built from the format names instead:
+//!
+//! ```
+//! use elevenlabs_rs::utils::transcode::{AudioEncoding, Transcoder};
+//!
+//! // The agent's `agent_output_audio_format` and the carrier's format
+//! let agent = AudioEncoding::parse("pcm_24000").unwrap();
+//! let mut transcoder = Transcoder::new(agent, AudioEncoding::Ulaw);
+//!
+//! // Chunks may split a sample; the odd byte is kept for the next chunk
+//! let first = transcoder.transcode(&[0u8; 481]);
+//! let second = transcoder.transcode(&[0u8; 479]);
+//! // At the end of the stream, take the audio held back for the next chunk
+//! let rest = transcoder.finish();
+//! assert_eq!(first.len() + second.len() + rest.len(), 160);
+//! ```
+use crate::shared::query_params::OutputFormat;
+use bytes::Bytes;
+
+const ULAW_BIAS: i32 = 0x84;
+const ULAW_CLIP: i32 = 32635;
+pub const ULAW_SAMPLE_RATE: u32 = 8000;
+
+/// Encode a single 16-bit PCM sample as G.711 μ-law
+pub fn linear_to_ulaw(sample: i16) -> u8 {
+    let mut s = sample as i32;
+    let sign = if s < 0 {
+        s = -s;
+        0x80
+    } else {
+        0x00
+    };
+    if s > ULAW_CLIP {
+        s = ULAW_CLIP;
+    }
+    s += ULAW_BIAS;
+
+    let mut exponent = 7;
+    let mut mask = 0x4000;
+    while exponent > 0 && s & mask == 0 {
+        exponent -= 1;
+        mask >>= 1;
+    }
+    let mantissa = (s >> (exponent + 3)) & 0x0F;
+    !(sign | (exponent << 4) | mantissa) as u8
+}
+
+/// Decode a single G.711 μ-law byte into a 16-bit PCM sample
+pub fn ulaw_to_linear(ulaw: u8) -> i16 {
+    let ulaw = !ulaw;
+    let sign = ulaw & 0x80;
+    let exponent = ((ulaw >> 4) & 0x07) as i32;
+    let mantissa = (ulaw & 0x0F) as i32;
+    let magnitude = (((mantissa << 3) + ULAW_BIAS) << exponent) - ULAW_BIAS;
+    if sign != 0 {
+        -magnitude as i16
+    } else {
+        magnitude as i16
+    }
+}
+
+/// Interpret raw bytes as 16-bit little-endian PCM samples.
+///
+/// A trailing odd byte is ignored.
+pub fn pcm_bytes_to_samples(pcm: &[u8]) -> Vec<i16> {
+    pcm.chunks_exact(2)
+        .map(|b| i16::from_le_bytes([b[0], b[1]]))
+        .collect()
+}
+
+/// Serialize 16-bit PCM samples as little-endian bytes
+pub fn samples_to_pcm_bytes(samples: &[i16]) -> Vec<u8> {
+    samples.iter().flat_map(|s| s.to_le_bytes()).collect()
+}
+
+/// Resample mono 16-bit PCM using linear interpolation
+///
+/// The output has one sample for every output period that starts within the input,
+/// `samples.len() * to_rate / from_rate` rounded up. A rate of 0 gives no output.
+pub fn resample(samples: &[i16], from_rate: u32, to_rate: u32) -> Vec<i16> {
+    if from_rate == 0 || to_rate == 0 {
+        return Vec::new();
+    }
+    if from_rate == to_rate || samples.is_empty() {
+        return samples.to_vec();
+    }
+    let mut resampler = Resampler::new(from_rate, to_rate);
+    let mut out = Vec::with_capacity(samples.len() * to_rate as usize / from_rate as usize + 1);
+    resampler.push(samples, &mut out);
+    resampler.finish(&mut out);
+    out
+}
+
+/// Linear interpolation over a stream of chunks
+///
+/// Output sample `i` sits at input position `i * from / to`. It is produced once both
+/// input samples around that position have arrived, so the position and the last input
+/// sample carry over from one chunk to the next, and the chunked output is the same as
+/// resampling all the input at once.
+#[derive(Clone, Debug)]
+struct Resampler {
+    from: u64,
+    to: u64,
+    /// The index of the next output sample
+    next_out: u64,
+    /// The number of input samples received
+    seen: u64,
+    /// The last input sample received
+    last: Option<i16>,
+}
+
+impl Resampler {
+    fn new(from_rate: u32, to_rate: u32) -> Self {
+        Resampler {
+            from: from_rate as u64,
+            to: to_rate as u64,
+            next_out: 0,
+            seen: 0,
+            last: None,
+        }
+    }
+
+    /// Resample the next chunk, holding back output that needs the next chunk's input
+    fn push(&mut self, samples: &[i16], out: &mut Vec<i16>) {
+        if self.from == 0 || self.to == 0 {
+            return;
+        }
+        let start = self.seen;
+        let previous = self.last;
+        self.seen += samples.len() as u64;
+        let sample = |index: u64| match index.checked_sub(start) {
+            Some(offset) => samples[offset as usize],
+            // Only the last sample of the previous chunk is ever needed again
+            None => previous.unwrap_or_default(),
+        };
+        loop {
+            let position = self.next_out * self.from;
+            let (index, remainder) = (position / self.to, position % self.to);
+            if remainder == 0 {
+                if index >= self.seen {
+                    break;
+                }
+                out.push(sample(index));
+            } else {
+                if index + 1 >= self.seen {
+                    break;
+                }
+                let (a, b) = (sample(index), sample(index + 1));
+                out.push(interpolate(a, b, remainder, self.to));
+            }
+            self.next_out += 1;
+        }
+        if let Some(&last) = samples.last() {
+            self.last = Some(last);
+        }
+    }
+
+    /// Produce the held back output, past the last input sample, at the end of the input
+    fn finish(&mut self, out: &mut Vec<i16>) {
+        let Some(last) = self.last.filter(|_| self.from != 0) else {
+            return;
+        };
+        while self.next_out * self.from < self.seen * self.to {
+            out.push(last);
+            self.next_out += 1;
+        }
+    }
+}
+
+fn interpolate(a: i16, b: i16, numerator: u64, denominator: u64) -> i16 {
+    let (a, b) = (a as f64, b as f64);
+    (a + (b - a) * numerator as f64 / denominator as f64).round() as i16
+}
+
+/// Transcode 16-bit little-endian PCM at `sample_rate` into 8kHz μ-law,
+/// e.g. agent audio on its way out to a caller.
+pub fn pcm_to_ulaw_8k(pcm: &[u8], sample_rate: u32) -> Bytes {
+    let samples = pcm_bytes_to_samples(pcm);
+    let samples = resample(&samples, sample_rate, ULAW_SAMPLE_RATE);
+    samples.into_iter().map(linear_to_ulaw).collect()
+}
+
+/// Transcode 8kHz μ-law into 16-bit little-endian PCM at `sample_rate`,
+/// e.g. caller audio on its way in to an agent.
+pub fn ulaw_8k_to_pcm(ulaw: &[u8], sample_rate: u32) -> Bytes {
+    let samples = ulaw.iter().map(|u| ulaw_to_linear(*u)).collect::<Vec<i16>>();
+    let samples = resample(&samples, ULAW_SAMPLE_RATE, sample_rate);
+    Bytes::from(samples_to_pcm_bytes(&samples))
+}
+
+/// A raw audio encoding that can be transcoded
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AudioEncoding {
+    /// 16-bit little-endian mono PCM
+    Pcm { sample_rate: u32 },
+    /// 8kHz G.711 μ-law
+    Ulaw,
+}
+
+impl AudioEncoding {
+    /// Parse a format name such as `"pcm_16000"` or `"ulaw_8000"`, as used by output
+    /// formats and agent audio settings.
+    ///
+    /// Returns `None` for compressed formats such as MP3, and for a sample rate of 0.
+    pub fn parse(format: &str) -> Option<Self> {
+        match format.split_once('_')? {
+            ("pcm", rate) => Some(AudioEncoding::Pcm {
+                sample_rate: rate.parse().ok().filter(|rate| *rate != 0)?,
+            }),
+            ("ulaw", "8000") => Some(AudioEncoding::Ulaw),
+            _ => None,
+        }
+    }
+    /// The encoding of `format`, `None` for MP3 formats
+    pub fn from_output_format(format: &OutputFormat) -> Option<Self> {
+        Self::parse(format.as_str())
+    }
+    pub fn sample_rate(&self) -> u32 {
+        match self {
+            AudioEncoding::Pcm { sample_rate } => *sample_rate,
+            AudioEncoding::Ulaw => ULAW_SAMPLE_RATE,
+        }
+    }
+}
+
+/// The format name, e.g. `"pcm_16000"`
+impl std::fmt::Display for AudioEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AudioEncoding::Pcm { sample_rate } => write!(f, "pcm_{}", sample_rate),
+            AudioEncoding::Ulaw => f.write_str("ulaw_8000"),
+        }
+    }
+}
+
+/// Converts a stream of audio chunks from one [AudioEncoding] to another
+///
+/// A byte left over from a PCM sample split across chunks is held back and prepended
+/// to the next chunk. When the sample rates differ, the resampling position and the
+/// last sample carry over too, so chunk boundaries don't drop samples, and the output
+/// of the last input sample is held back until [finish](Transcoder::finish). When both
+/// encodings are the same, chunks pass through unchanged.
+#[derive(Clone, Debug)]
+pub struct Transcoder {
+    from: AudioEncoding,
+    to: AudioEncoding,
+    leftover: Option<u8>,
+    resampler: Resampler,
+}
+
+impl Transcoder {
+    pub fn new(from: AudioEncoding, to: AudioEncoding) -> Self {
+        Transcoder {
+            from,
+            to,
+            leftover: None,
+            resampler: Resampler::new(from.sample_rate(), to.sample_rate()),
+        }
+    }
+    pub fn input(&self) -> AudioEncoding {
+        self.from
+    }
+    pub fn output(&self) -> AudioEncoding {
+        self.to
+    }
+    /// Whether chunks are passed through unchanged
+    pub fn is_passthrough(&self) -> bool {
+        self.from == self.to
+    }
+    /// Transcode the next chunk of audio
+    pub fn transcode(&mut self, chunk: &[u8]) -> Bytes {
+        if self.is_passthrough() {
+            return Bytes::copy_from_slice(chunk);
+        }
+        let samples = match self.from {
+            AudioEncoding::Ulaw => chunk.iter().map(|u| ulaw_to_linear(*u)).collect(),
+            AudioEncoding::Pcm { .. } => {
+                let mut pcm = Vec::with_capacity(chunk.len() + 1);
+                pcm.extend(self.leftover.take());
+                pcm.extend_from_slice(chunk);
+                if pcm.len() % 2 == 1 {
+                    self.leftover = pcm.pop();
+                }
+                pcm_bytes_to_samples(&pcm)
+            }
+        };
+        let mut resampled = Vec::with_capacity(samples.len());
+        self.resampler.push(&samples, &mut resampled);
+        self.encode(resampled)
+    }
+    /// The audio held back at the end of the stream, after the last chunk
+    pub fn finish(&mut self) -> Bytes {
+        if self.is_passthrough() {
+            return Bytes::new();
+        }
+        let mut resampled = Vec::new();
+        self.resampler.finish(&mut resampled);
+        self.encode(resampled)
+    }
+
+    fn encode(&self, samples: Vec<i16>) -> Bytes {
+        match self.to {
+            AudioEncoding::Ulaw => samples.into_iter().map(linear_to_ulaw).collect(),
+            AudioEncoding::Pcm { .. } => Bytes::from(samples_to_pcm_bytes(&samples)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A deterministic, non-constant signal so dropped or repeated samples show up
+    fn signal(len: usize) -> Vec<i16> {
+        (0..len)
+            .map(|i| ((i * 7919) % 20000) as i16 - 10000)
+            .collect()
+    }
+
+    fn transcode_in_chunks(
+        from: AudioEncoding,
+        to: AudioEncoding,
+        input: &[u8],
+        chunk_sizes: &[usize],
+    ) -> Vec<u8> {
+        let mut transcoder = Transcoder::new(from, to);
+        let mut out = Vec::new();
+        let mut rest = input;
+        for &size in chunk_sizes.iter().cycle() {
+            if rest.is_empty() {
+                break;
+            }
+            let (chunk, tail) = rest.split_at(size.min(rest.len()));
+            out.extend_from_slice(&transcoder.transcode(chunk));
+            rest = tail;
+        }
+        out.extend_from_slice(&transcoder.finish());
+        out
+    }
+
+    #[test]
+    fn uneven_pcm_chunks_match_one_shot() {
+        let samples = signal(2205);
+        let pcm = samples_to_pcm_bytes(&samples);
+        for (from, to) in [(22050, 8000), (16000, 8000), (24000, 16000), (8000, 22050)] {
+            let expected = samples_to_pcm_bytes(&resample(&samples, from, to));
+            let out = transcode_in_chunks(
+                AudioEncoding::Pcm { sample_rate: from },
+                AudioEncoding::Pcm { sample_rate: to },
+                &pcm,
+                &[333, 1, 64, 7, 1000],
+            );
+            assert_eq!(out.len(), expected.len(), "{from} -> {to}");
+            assert_eq!(out, expected, "{from} -> {to}");
+        }
+    }
+
+    #[test]
+    fn uneven_ulaw_chunks_match_one_shot() {
+        let ulaw = signal(1001)
+            .into_iter()
+            .map(linear_to_ulaw)
+            .collect::<Vec<_>>();
+        let expected = ulaw_8k_to_pcm(&ulaw, 22050);
+        let out = transcode_in_chunks(
+            AudioEncoding::Ulaw,
+            AudioEncoding::Pcm { sample_rate: 22050 },
+            &ulaw,
+            &[159, 3, 161],
+        );
+        assert_eq!(out.len(), expected.len());
+        assert_eq!(out, expected.to_vec());
+    }
+
+    #[test]
+    fn zero_sample_rate() {
+        assert_eq!(AudioEncoding::parse("pcm_0"), None);
+        assert!(resample(&signal(100), 0, 8000).is_empty());
+        assert!(resample(&signal(100), 8000, 0).is_empty());
+        let mut transcoder =
+            Transcoder::new(AudioEncoding::Pcm { sample_rate: 0 }, AudioEncoding::Ulaw);
+        assert!(transcoder.transcode(&[0u8; 64]).is_empty());
+        assert!(transcoder.finish().is_empty());
+    }
+
+    #[test]
+    fn resample_length() {
+        assert_eq!(resample(&signal(320), 16000, 8000).len(), 160);
+        assert_eq!(resample(&signal(160), 8000, 16000).len(), 320);
+        assert_eq!(resample(&signal(441), 22050, 8000).len(), 160);
+        // 0.5 of an output period past the last full one starts within the input
+        assert_eq!(resample(&signal(3), 2, 1).len(), 2);
+    }
+}
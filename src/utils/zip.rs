@@ -0,0 +1,490 @@
+//! A minimal zip reader for archives returned by the API
+//!
+//! [DownloadHistoryItems](crate::endpoints::history::DownloadHistoryItems) returns a zip
+//! archive when more than one item is requested. Only what those archives use is
+//! supported: stored and deflated entries, without encryption or zip64.
+use crate::client::Result;
+use crate::error::Error;
+use bytes::Bytes;
+use std::collections::HashMap;
+
+const EOCD_SIGNATURE: u32 = 0x0605_4b50;
+const CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0201_4b50;
+const LOCAL_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const EOCD_LEN: usize = 22;
+const STORED: u16 = 0;
+const DEFLATED: u16 = 8;
+
+/// Extract every file in a zip archive into a map of file name to contents
+///
+/// Names are returned as stored in the archive and may contain directories or `..`,
+/// so strip them to a file name before using them as paths.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::{save, unzip};
+/// use std::path::Path;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let ids = vec!["some_history_item_id".to_string(), "another_id".to_string()];
+///     let archive = c.hit(DownloadHistoryItems::new(DownloadBody::new(ids))).await?;
+///     for (file_name, audio) in unzip(&archive)? {
+///         let Some(file_name) = Path::new(&file_name).file_name() else {
+///             continue;
+///         };
+///         save(&file_name.to_string_lossy(), audio)?;
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn unzip(archive: &[u8]) -> Result<HashMap<String, Bytes>> {
+    let eocd = (0..=archive.len().saturating_sub(EOCD_LEN))
+        .rev()
+        .find(|&i| read_u32(archive, i) == Some(EOCD_SIGNATURE))
+        .ok_or_else(|| invalid("end of central directory not found"))?;
+    let entries = read_u16(archive, eocd + 10).ok_or_else(|| invalid("truncated archive"))?;
+    let mut offset =
+        read_u32(archive, eocd + 16).ok_or_else(|| invalid("truncated archive"))? as usize;
+
+    let mut files = HashMap::new();
+    for _ in 0..entries {
+        if read_u32(archive, offset) != Some(CENTRAL_DIRECTORY_SIGNATURE) {
+            return Err(invalid("bad central directory entry"));
+        }
+        let field = |at: usize| read_u16(archive, offset + at).map(usize::from);
+        let truncated = || invalid("truncated central directory entry");
+        let method = read_u16(archive, offset + 10).ok_or_else(truncated)?;
+        let crc = read_u32(archive, offset + 16).ok_or_else(truncated)?;
+        let compressed_size = read_u32(archive, offset + 20).ok_or_else(truncated)? as usize;
+        let size = read_u32(archive, offset + 24).ok_or_else(truncated)? as usize;
+        let name_len = field(28).ok_or_else(truncated)?;
+        let extra_len = field(30).ok_or_else(truncated)?;
+        let comment_len = field(32).ok_or_else(truncated)?;
+        let local_offset = read_u32(archive, offset + 42).ok_or_else(truncated)? as usize;
+        let name = archive
+            .get(offset + 46..offset + 46 + name_len)
+            .ok_or_else(truncated)?;
+        let name = String::from_utf8_lossy(name).into_owned();
+        offset += 46 + name_len + extra_len + comment_len;
+
+        if name.ends_with('/') {
+            continue;
+        }
+        if read_u32(archive, local_offset) != Some(LOCAL_HEADER_SIGNATURE) {
+            return Err(invalid("bad local file header"));
+        }
+        let truncated = || invalid("truncated local file header");
+        let local_name_len = read_u16(archive, local_offset + 26).ok_or_else(truncated)?;
+        let local_extra_len = read_u16(archive, local_offset + 28).ok_or_else(truncated)?;
+        let start = local_offset + 30 + local_name_len as usize + local_extra_len as usize;
+        let data = archive
+            .get(start..start + compressed_size)
+            .ok_or_else(|| invalid("truncated file data"))?;
+
+        let contents = match method {
+            STORED => data.to_vec(),
+            DEFLATED => inflate(data, size)?,
+            _ => return Err(invalid("unsupported compression method")),
+        };
+        if contents.len() != size {
+            return Err(invalid("file size mismatch"));
+        }
+        if crc32(&contents) != crc {
+            return Err(invalid("CRC-32 mismatch"));
+        }
+        files.insert(name, Bytes::from(contents));
+    }
+    Ok(files)
+}
+
+fn invalid(reason: &str) -> Box<dyn std::error::Error + Send + Sync> {
+    Box::new(Error::InvalidZipArchive(reason.to_string()))
+}
+
+/// The CRC-32 (IEEE) of `data`, as stored in zip headers
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, &byte| {
+        (0..8).fold(crc ^ byte as u32, |crc, _| {
+            (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg())
+        })
+    })
+}
+
+fn read_u16(data: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(data.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn read_u32(data: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(at..at + 4)?.try_into().ok()?))
+}
+
+// Raw DEFLATE (RFC 1951) decoding
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+const MAX_BITS: usize = 15;
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    bit_buffer: u32,
+    bit_count: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        BitReader {
+            data,
+            position: 0,
+            bit_buffer: 0,
+            bit_count: 0,
+        }
+    }
+
+    fn bits(&mut self, count: u32) -> Result<u32> {
+        while self.bit_count < count {
+            let byte = *self
+                .data
+                .get(self.position)
+                .ok_or_else(|| invalid("unexpected end of deflate stream"))?;
+            self.position += 1;
+            self.bit_buffer |= (byte as u32) << self.bit_count;
+            self.bit_count += 8;
+        }
+        let value = self.bit_buffer & ((1u64 << count) - 1) as u32;
+        self.bit_buffer >>= count;
+        self.bit_count -= count;
+        Ok(value)
+    }
+
+    fn align_to_byte(&mut self) {
+        self.bit_buffer = 0;
+        self.bit_count = 0;
+    }
+}
+
+/// A canonical Huffman code, stored as the number of codes per length and the
+/// symbols ordered by code
+struct Huffman {
+    counts: [u16; MAX_BITS + 1],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Self {
+        let mut counts = [0u16; MAX_BITS + 1];
+        for &length in lengths {
+            counts[length as usize] += 1;
+        }
+        counts[0] = 0;
+        let mut offsets = [0u16; MAX_BITS + 2];
+        for length in 1..=MAX_BITS {
+            offsets[length + 1] = offsets[length] + counts[length];
+        }
+        let mut symbols = vec![0u16; lengths.len()];
+        for (symbol, &length) in lengths.iter().enumerate() {
+            if length != 0 {
+                symbols[offsets[length as usize] as usize] = symbol as u16;
+                offsets[length as usize] += 1;
+            }
+        }
+        Huffman { counts, symbols }
+    }
+
+    fn decode(&self, reader: &mut BitReader) -> Result<u16> {
+        let mut code: i32 = 0;
+        let mut first: i32 = 0;
+        let mut index: i32 = 0;
+        for length in 1..=MAX_BITS {
+            code |= reader.bits(1)? as i32;
+            let count = self.counts[length] as i32;
+            if code - count < first {
+                return Ok(self.symbols[(index + (code - first)) as usize]);
+            }
+            index += count;
+            first += count;
+            first <<= 1;
+            code <<= 1;
+        }
+        Err(invalid("invalid huffman code"))
+    }
+}
+
+/// Inflate `data`, failing once the output grows past `limit` bytes
+fn inflate(data: &[u8], limit: usize) -> Result<Vec<u8>> {
+    let mut reader = BitReader::new(data);
+    let mut out = Vec::with_capacity(limit.min(data.len() * 4));
+    loop {
+        let last = reader.bits(1)? == 1;
+        match reader.bits(2)? {
+            0 => inflate_stored(&mut reader, &mut out, limit)?,
+            1 => {
+                let (literals, distances) = fixed_codes();
+                inflate_block(&mut reader, &mut out, limit, &literals, &distances)?
+            }
+            2 => {
+                let (literals, distances) = dynamic_codes(&mut reader)?;
+                inflate_block(&mut reader, &mut out, limit, &literals, &distances)?
+            }
+            _ => return Err(invalid("invalid deflate block type")),
+        }
+        if last {
+            return Ok(out);
+        }
+    }
+}
+
+fn inflate_stored(reader: &mut BitReader, out: &mut Vec<u8>, limit: usize) -> Result<()> {
+    reader.align_to_byte();
+    let at = reader.position;
+    let len = read_u16(reader.data, at).ok_or_else(|| invalid("truncated stored block"))?;
+    let nlen = read_u16(reader.data, at + 2).ok_or_else(|| invalid("truncated stored block"))?;
+    if len != !nlen {
+        return Err(invalid("corrupt stored block length"));
+    }
+    let start = at + 4;
+    let block = reader
+        .data
+        .get(start..start + len as usize)
+        .ok_or_else(|| invalid("truncated stored block"))?;
+    if out.len() + block.len() > limit {
+        return Err(too_large());
+    }
+    out.extend_from_slice(block);
+    reader.position = start + len as usize;
+    Ok(())
+}
+
+fn too_large() -> Box<dyn std::error::Error + Send + Sync> {
+    invalid("entry inflates past its uncompressed size")
+}
+
+fn fixed_codes() -> (Huffman, Huffman) {
+    let mut lengths = [0u8; 288];
+    lengths[..144].fill(8);
+    lengths[144..256].fill(9);
+    lengths[256..280].fill(7);
+    lengths[280..].fill(8);
+    (Huffman::new(&lengths), Huffman::new(&[5u8; 30]))
+}
+
+fn dynamic_codes(reader: &mut BitReader) -> Result<(Huffman, Huffman)> {
+    let literal_count = reader.bits(5)? as usize + 257;
+    let distance_count = reader.bits(5)? as usize + 1;
+    let code_length_count = reader.bits(4)? as usize + 4;
+
+    let mut code_lengths = [0u8; 19];
+    for &index in CODE_LENGTH_ORDER.iter().take(code_length_count) {
+        code_lengths[index] = reader.bits(3)? as u8;
+    }
+    let code_length_code = Huffman::new(&code_lengths);
+
+    let mut lengths = vec![0u8; literal_count + distance_count];
+    let mut index = 0;
+    while index < lengths.len() {
+        let symbol = code_length_code.decode(reader)?;
+        let (value, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => {
+                let previous = *index
+                    .checked_sub(1)
+                    .and_then(|i| lengths.get(i))
+                    .ok_or_else(|| invalid("repeat without a previous length"))?;
+                (previous, 3 + reader.bits(2)? as usize)
+            }
+            17 => (0, 3 + reader.bits(3)? as usize),
+            _ => (0, 11 + reader.bits(7)? as usize),
+        };
+        if index + repeat > lengths.len() {
+            return Err(invalid("too many code lengths"));
+        }
+        lengths[index..index + repeat].fill(value);
+        index += repeat;
+    }
+    Ok((
+        Huffman::new(&lengths[..literal_count]),
+        Huffman::new(&lengths[literal_count..]),
+    ))
+}
+
+fn inflate_block(
+    reader: &mut BitReader,
+    out: &mut Vec<u8>,
+    limit: usize,
+    literals: &Huffman,
+    distances: &Huffman,
+) -> Result<()> {
+    loop {
+        let symbol = literals.decode(reader)? as usize;
+        match symbol {
+            0..=255 if out.len() == limit => return Err(too_large()),
+            0..=255 => out.push(symbol as u8),
+            256 => return Ok(()),
+            _ => {
+                let index = symbol - 257;
+                if index >= LENGTH_BASE.len() {
+                    return Err(invalid("invalid length symbol"));
+                }
+                let length =
+                    LENGTH_BASE[index] as usize + reader.bits(LENGTH_EXTRA[index] as u32)? as usize;
+                let index = distances.decode(reader)? as usize;
+                if index >= DISTANCE_BASE.len() {
+                    return Err(invalid("invalid distance symbol"));
+                }
+                let distance = DISTANCE_BASE[index] as usize
+                    + reader.bits(DISTANCE_EXTRA[index] as u32)? as usize;
+                if distance > out.len() {
+                    return Err(invalid("distance too far back"));
+                }
+                if out.len() + length > limit {
+                    return Err(too_large());
+                }
+                let start = out.len() - distance;
+                for i in 0..length {
+                    out.push(out[start + i]);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A directory entry `audio/` and a stored `audio/a.mp3`
+    const STORED_ARCHIVE: &[u8] = &[
+        0x50, 0x4b, 0x03, 0x04, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x61, 0x75, 0x64, 0x69, 0x6f, 0x2f, 0x50, 0x4b, 0x03, 0x04, 0x14, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x23, 0x02, 0x42, 0xa6, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x61, 0x75, 0x64, 0x69, 0x6f, 0x2f, 0x61, 0x2e, 0x6d,
+        0x70, 0x33, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x61, 0x75, 0x64, 0x69, 0x6f, 0x50,
+        0x4b, 0x01, 0x02, 0x14, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x61, 0x75, 0x64, 0x69, 0x6f, 0x2f, 0x50, 0x4b, 0x01, 0x02, 0x14, 0x00, 0x14, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x23, 0x02, 0x42, 0xa6, 0x0c, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x61, 0x75, 0x64, 0x69, 0x6f, 0x2f, 0x61, 0x2e,
+        0x6d, 0x70, 0x33, 0x50, 0x4b, 0x05, 0x06, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x02, 0x00,
+        0x6d, 0x00, 0x00, 0x00, 0x59, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+    /// `a.mp3` deflated with fixed Huffman codes
+    const FIXED_ARCHIVE: &[u8] = &[
+        0x50, 0x4b, 0x03, 0x04, 0x14, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x94,
+        0xd0, 0x86, 0x8f, 0x14, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x61, 0x2e, 0x6d, 0x70, 0x33, 0x4b, 0xcb, 0xac, 0x48, 0x4d, 0x51, 0xc8, 0x28, 0x4d, 0x4b,
+        0xcb, 0x4d, 0xcc, 0xd3, 0x51, 0x48, 0xc3, 0xc3, 0x05, 0x00, 0x50, 0x4b, 0x01, 0x02, 0x14,
+        0x00, 0x14, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x94, 0xd0, 0x86, 0x8f,
+        0x14, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x61, 0x2e, 0x6d, 0x70,
+        0x33, 0x50, 0x4b, 0x05, 0x06, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x33, 0x00,
+        0x00, 0x00, 0x37, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+    /// `b.mp3` deflated with dynamic Huffman codes
+    const DYNAMIC_ARCHIVE: &[u8] = &[
+        0x50, 0x4b, 0x03, 0x04, 0x14, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4a,
+        0xf2, 0xc1, 0x3e, 0x57, 0x00, 0x00, 0x00, 0xf8, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x62, 0x2e, 0x6d, 0x70, 0x33, 0xcd, 0xc9, 0xbb, 0x0d, 0xc3, 0x30, 0x0c, 0x05, 0xc0, 0x55,
+        0xde, 0x1c, 0xe9, 0xd2, 0x65, 0x0d, 0x8a, 0x7c, 0xfa, 0xc4, 0x16, 0x59, 0x88, 0x02, 0xec,
+        0xed, 0x8d, 0x6c, 0x91, 0xee, 0x80, 0x7b, 0xc3, 0x6e, 0x97, 0x39, 0x14, 0x9f, 0x5d, 0xeb,
+        0x14, 0x47, 0x39, 0x43, 0x0f, 0x38, 0x69, 0x0b, 0xf4, 0xd8, 0xad, 0x23, 0x79, 0x25, 0xb2,
+        0x4b, 0x42, 0xf7, 0xca, 0x98, 0xd0, 0x30, 0xe2, 0xa4, 0xb7, 0xec, 0x0b, 0x85, 0xf2, 0x5b,
+        0xa2, 0x8e, 0x8b, 0x86, 0x70, 0xae, 0x17, 0xa4, 0xdb, 0xd1, 0xf4, 0x5b, 0xcb, 0xe0, 0x5f,
+        0xf2, 0x01, 0x50, 0x4b, 0x01, 0x02, 0x14, 0x00, 0x14, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x4a, 0xf2, 0xc1, 0x3e, 0x57, 0x00, 0x00, 0x00, 0xf8, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x62, 0x2e, 0x6d, 0x70, 0x33, 0x50, 0x4b, 0x05, 0x06, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x01, 0x00, 0x33, 0x00, 0x00, 0x00, 0x7a, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    fn is_invalid(result: Result<HashMap<String, Bytes>>) -> bool {
+        matches!(
+            result.unwrap_err().downcast_ref::<Error>(),
+            Some(Error::InvalidZipArchive(_))
+        )
+    }
+
+    #[test]
+    fn stored_entries_skip_directories() {
+        let files = unzip(STORED_ARCHIVE).unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files["audio/a.mp3"], "stored audio");
+    }
+
+    #[test]
+    fn fixed_huffman() {
+        let files = unzip(FIXED_ARCHIVE).unwrap();
+        assert_eq!(
+            files["a.mp3"],
+            "fixed huffman, fixed huffman, fixed huffman"
+        );
+    }
+
+    #[test]
+    fn dynamic_huffman() {
+        let mut expected = b"A dynamic Huffman block needs enough text that \
+            custom code lengths beat the fixed ones: "
+            .to_vec();
+        expected.extend((0..160u32).map(|i| (i * 7 % 11) as u8 + b'a'));
+        let files = unzip(DYNAMIC_ARCHIVE).unwrap();
+        assert_eq!(files["b.mp3"], expected);
+    }
+
+    #[test]
+    fn truncated_or_corrupt() {
+        assert!(is_invalid(unzip(&[])));
+        assert!(is_invalid(unzip(
+            &STORED_ARCHIVE[..STORED_ARCHIVE.len() - 1]
+        )));
+        let eocd = DYNAMIC_ARCHIVE.len() - EOCD_LEN;
+        let mut truncated = DYNAMIC_ARCHIVE[..60].to_vec();
+        truncated.extend(&DYNAMIC_ARCHIVE[eocd..]);
+        assert!(is_invalid(unzip(&truncated)));
+        let mut corrupt = FIXED_ARCHIVE.to_vec();
+        // local header (30 bytes) and the name, then set the reserved block type 3
+        corrupt[30 + 5] |= 0b110;
+        assert!(is_invalid(unzip(&corrupt)));
+    }
+
+    #[test]
+    fn crc_mismatch() {
+        let mut corrupt = STORED_ARCHIVE.to_vec();
+        let at = corrupt
+            .windows(12)
+            .position(|w| w == b"stored audio")
+            .unwrap();
+        corrupt[at] = b'S';
+        assert!(is_invalid(unzip(&corrupt)));
+    }
+
+    #[test]
+    fn inflating_past_the_uncompressed_size() {
+        let mut corrupt = FIXED_ARCHIVE.to_vec();
+        let central_directory = read_u32(&corrupt, corrupt.len() - EOCD_LEN + 16).unwrap();
+        // Claim 10 bytes for an entry that inflates to 44
+        corrupt[central_directory as usize + 24] = 10;
+        assert!(is_invalid(unzip(&corrupt)));
+        assert!(inflate(&FIXED_ARCHIVE[30 + 5..], 10).is_err());
+    }
+}
@@ -0,0 +1,422 @@
+//! Webhook verification and payloads
+//!
+//! ElevenLabs signs each webhook with the workspace's webhook secret. The
+//! `ElevenLabs-Signature` header holds a timestamp and an HMAC-SHA256 of
+//! `"{timestamp}.{body}"`, as `t=<timestamp>,v0=<hex digest>`.
+//!
+//! [verify_and_parse] checks the signature and timestamp and deserializes the body. It
+//! only needs the headers and the raw body, so it works from any web framework.
+//!
+//! # Example
+//! ```no_run
+//! use elevenlabs_rs::webhooks::{verify_and_parse, PostCallPayload};
+//! use axum::{body::Bytes, http::{HeaderMap, StatusCode}};
+//!
+//! async fn post_call(headers: HeaderMap, body: Bytes) -> StatusCode {
+//!     let secret = std::env::var("WEBHOOK_SECRET").unwrap();
+//!     match verify_and_parse::<PostCallPayload>(&headers, &body, &secret) {
+//!         Ok(payload) => {
+//!             println!("conversation {} ended", payload.data().conversation_id());
+//!             StatusCode::OK
+//!         }
+//!         Err(_) => StatusCode::UNAUTHORIZED,
+//!     }
+//! }
+//! ```
+#![allow(dead_code)]
+use crate::client::Result;
+use crate::error::WebhookError;
+use reqwest::header::HeaderMap;
+use ring::hmac;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const SIGNATURE_HEADER: &str = "elevenlabs-signature";
+/// How old a webhook may be before it is rejected as a replay
+pub const TOLERANCE: Duration = Duration::from_secs(30 * 60);
+
+/// Verify the signature of a webhook and deserialize its body
+pub fn verify_and_parse<T: DeserializeOwned>(
+    headers: &HeaderMap,
+    body: &[u8],
+    secret: &str,
+) -> Result<T> {
+    verify(headers, body, secret)?;
+    Ok(serde_json::from_slice(body)?)
+}
+
+/// Verify the signature of a webhook against the current time
+pub fn verify(
+    headers: &HeaderMap,
+    body: &[u8],
+    secret: &str,
+) -> std::result::Result<(), WebhookError> {
+    let signature = headers
+        .get(SIGNATURE_HEADER)
+        .ok_or(WebhookError::MissingSignature)?
+        .to_str()
+        .map_err(|_| WebhookError::MalformedSignature)?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    verify_signature(signature, body, secret, now)
+}
+
+/// Verify an `ElevenLabs-Signature` header value, with `now` in Unix seconds
+pub fn verify_signature(
+    signature: &str,
+    body: &[u8],
+    secret: &str,
+    now: u64,
+) -> std::result::Result<(), WebhookError> {
+    let mut timestamp = None;
+    let mut digest = None;
+    for part in signature.split(',') {
+        match part.trim().split_once('=') {
+            Some(("t", value)) => timestamp = Some(value),
+            Some(("v0", value)) => digest = Some(value),
+            _ => {}
+        }
+    }
+    let (Some(timestamp), Some(digest)) = (timestamp, digest) else {
+        return Err(WebhookError::MalformedSignature);
+    };
+    let sent_at = timestamp
+        .parse::<u64>()
+        .map_err(|_| WebhookError::MalformedSignature)?;
+    if now.saturating_sub(sent_at) > TOLERANCE.as_secs() {
+        return Err(WebhookError::TimestampOutOfTolerance);
+    }
+    let digest = decode_hex(digest).ok_or(WebhookError::MalformedSignature)?;
+
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let mut message = Vec::with_capacity(timestamp.len() + 1 + body.len());
+    message.extend_from_slice(timestamp.as_bytes());
+    message.push(b'.');
+    message.extend_from_slice(body);
+    hmac::verify(&key, &message, &digest).map_err(|_| WebhookError::InvalidSignature)
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// A webhook of any type, for endpoints that receive more than one
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::webhooks::{verify_and_parse, WebhookEvent};
+/// use reqwest::header::HeaderMap;
+///
+/// fn handle(headers: &HeaderMap, body: &[u8], secret: &str) -> elevenlabs_rs::Result<()> {
+///     match verify_and_parse::<WebhookEvent>(headers, body, secret)? {
+///         WebhookEvent::PostCallTranscription(payload) => {
+///             println!("call {} ended", payload.data().conversation_id())
+///         }
+///         WebhookEvent::VoiceRemovalNotice(payload) => {
+///             println!("voice {} will be removed", payload.data().voice_id())
+///         }
+///         WebhookEvent::SpeechToTextTranscription(payload) => {
+///             let transcript = elevenlabs_rs::Transcript::from_webhook(payload.data())?;
+///             println!("{}", transcript.text())
+///         }
+///         WebhookEvent::Unknown(value) => println!("unhandled webhook: {}", value),
+///         _ => {}
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum WebhookEvent {
+    PostCallTranscription(PostCallPayload),
+    VoiceRemovalNotice(WebhookPayload<VoiceRemovalNotice>),
+    /// Sent when a [SpeechToTextAsync](crate::SpeechToTextAsync) transcription completes,
+    /// see [Transcript::from_webhook](crate::Transcript::from_webhook)
+    SpeechToTextTranscription(WebhookPayload<Value>),
+    /// A webhook type without a variant of its own, as sent
+    Unknown(Value),
+}
+
+impl WebhookEvent {
+    /// The `type` field of the webhook
+    pub fn r#type(&self) -> Option<&str> {
+        match self {
+            WebhookEvent::PostCallTranscription(payload) => Some(payload.r#type()),
+            WebhookEvent::VoiceRemovalNotice(payload) => Some(payload.r#type()),
+            WebhookEvent::SpeechToTextTranscription(payload) => Some(payload.r#type()),
+            WebhookEvent::Unknown(value) => value.get("type").and_then(Value::as_str),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for WebhookEvent {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        use serde::de::Error;
+        let value = Value::deserialize(deserializer)?;
+        let event = match value.get("type").and_then(Value::as_str) {
+            Some("post_call_transcription") => WebhookEvent::PostCallTranscription(
+                serde_json::from_value(value).map_err(D::Error::custom)?,
+            ),
+            Some("voice_removal_notice") => WebhookEvent::VoiceRemovalNotice(
+                serde_json::from_value(value).map_err(D::Error::custom)?,
+            ),
+            Some("speech_to_text_transcription") => WebhookEvent::SpeechToTextTranscription(
+                serde_json::from_value(value).map_err(D::Error::custom)?,
+            ),
+            _ => WebhookEvent::Unknown(value),
+        };
+        Ok(event)
+    }
+}
+
+impl Serialize for WebhookEvent {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            WebhookEvent::PostCallTranscription(payload) => payload.serialize(serializer),
+            WebhookEvent::VoiceRemovalNotice(payload) => payload.serialize(serializer),
+            WebhookEvent::SpeechToTextTranscription(payload) => payload.serialize(serializer),
+            WebhookEvent::Unknown(value) => value.serialize(serializer),
+        }
+    }
+}
+
+/// The envelope shared by all webhooks
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct WebhookPayload<D> {
+    r#type: String,
+    event_timestamp: u64,
+    data: D,
+}
+
+impl<D> WebhookPayload<D> {
+    pub fn r#type(&self) -> &str {
+        &self.r#type
+    }
+    pub fn event_timestamp(&self) -> u64 {
+        self.event_timestamp
+    }
+    pub fn data(&self) -> &D {
+        &self.data
+    }
+    pub fn into_data(self) -> D {
+        self.data
+    }
+}
+
+/// The `post_call_transcription` webhook, sent when a conversation ends
+pub type PostCallPayload = WebhookPayload<PostCallData>;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PostCallData {
+    agent_id: String,
+    conversation_id: String,
+    status: String,
+    #[serde(default)]
+    transcript: Vec<Value>,
+    metadata: Option<Value>,
+    analysis: Option<Value>,
+    conversation_initiation_client_data: Option<Value>,
+}
+
+impl PostCallData {
+    pub fn agent_id(&self) -> &str {
+        &self.agent_id
+    }
+    pub fn conversation_id(&self) -> &str {
+        &self.conversation_id
+    }
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+    pub fn transcript(&self) -> &[Value] {
+        &self.transcript
+    }
+    pub fn metadata(&self) -> Option<&Value> {
+        self.metadata.as_ref()
+    }
+    pub fn analysis(&self) -> Option<&Value> {
+        self.analysis.as_ref()
+    }
+    pub fn conversation_initiation_client_data(&self) -> Option<&Value> {
+        self.conversation_initiation_client_data.as_ref()
+    }
+}
+
+/// The data of a `voice_removal_notice` webhook, sent ahead of a library voice being
+/// removed
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct VoiceRemovalNotice {
+    voice_id: String,
+    /// Fields other than the voice ID, which vary between notices
+    #[serde(flatten)]
+    details: serde_json::Map<String, Value>,
+}
+
+impl VoiceRemovalNotice {
+    pub fn voice_id(&self) -> &str {
+        &self.voice_id
+    }
+    pub fn details(&self) -> &serde_json::Map<String, Value> {
+        &self.details
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "whsec_test";
+    const BODY: &[u8] = br#"{"type":"test"}"#;
+    const SENT_AT: u64 = 1_700_000_000;
+    const SIGNATURE: &str =
+        "t=1700000000,v0=5f32b1f20af8adf84aaaa8ffc92da0441bf4c05562faa71f5c27352c22b106a8";
+
+    #[test]
+    fn valid_signature() {
+        assert_eq!(
+            verify_signature(SIGNATURE, BODY, SECRET, SENT_AT + 60),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn wrong_secret() {
+        assert_eq!(
+            verify_signature(SIGNATURE, BODY, "whsec_other", SENT_AT),
+            Err(WebhookError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn tampered_body() {
+        assert_eq!(
+            verify_signature(SIGNATURE, br#"{"type":"tost"}"#, SECRET, SENT_AT),
+            Err(WebhookError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn stale_timestamp() {
+        let now = SENT_AT + TOLERANCE.as_secs() + 1;
+        assert_eq!(
+            verify_signature(SIGNATURE, BODY, SECRET, now),
+            Err(WebhookError::TimestampOutOfTolerance)
+        );
+    }
+
+    #[test]
+    fn malformed_signature() {
+        let (timestamp, digest) = SIGNATURE.split_once(',').unwrap();
+        for signature in [
+            timestamp.to_string(),
+            digest.to_string(),
+            format!("t=soon,{digest}"),
+            format!("{timestamp},v0=abc"),
+            format!("{timestamp},v0={}", "zz".repeat(32)),
+        ] {
+            assert_eq!(
+                verify_signature(&signature, BODY, SECRET, SENT_AT),
+                Err(WebhookError::MalformedSignature),
+                "{signature}"
+            );
+        }
+    }
+
+    #[test]
+    fn missing_header() {
+        assert_eq!(
+            verify(&HeaderMap::new(), BODY, SECRET),
+            Err(WebhookError::MissingSignature)
+        );
+    }
+
+    fn round_trip(value: Value) -> WebhookEvent {
+        let event = serde_json::from_value::<WebhookEvent>(value.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&event).unwrap(), value);
+        event
+    }
+
+    #[test]
+    fn post_call_transcription() {
+        let event = round_trip(serde_json::json!({
+            "type": "post_call_transcription",
+            "event_timestamp": SENT_AT,
+            "data": {
+                "agent_id": "agent",
+                "conversation_id": "conversation",
+                "status": "done",
+                "transcript": [],
+                "metadata": null,
+                "analysis": null,
+                "conversation_initiation_client_data": null
+            }
+        }));
+        let WebhookEvent::PostCallTranscription(payload) = &event else {
+            panic!("{event:?}");
+        };
+        assert_eq!(payload.data().conversation_id(), "conversation");
+        assert_eq!(event.r#type(), Some("post_call_transcription"));
+    }
+
+    #[test]
+    fn voice_removal_notice() {
+        let event = round_trip(serde_json::json!({
+            "type": "voice_removal_notice",
+            "event_timestamp": SENT_AT,
+            "data": { "voice_id": "voice", "removal_date": "2026-01-01" }
+        }));
+        let WebhookEvent::VoiceRemovalNotice(payload) = &event else {
+            panic!("{event:?}");
+        };
+        assert_eq!(payload.data().voice_id(), "voice");
+        assert_eq!(payload.data().details()["removal_date"], "2026-01-01");
+        assert_eq!(event.r#type(), Some("voice_removal_notice"));
+    }
+
+    #[test]
+    fn speech_to_text_transcription() {
+        let event = round_trip(serde_json::json!({
+            "type": "speech_to_text_transcription",
+            "event_timestamp": SENT_AT,
+            "data": { "transcription": { "text": "hello" } }
+        }));
+        assert!(matches!(event, WebhookEvent::SpeechToTextTranscription(_)));
+        assert_eq!(event.r#type(), Some("speech_to_text_transcription"));
+    }
+
+    #[test]
+    fn unknown_type_is_kept() {
+        let value = serde_json::json!({ "type": "agent_updated", "data": { "id": 1 } });
+        let event = round_trip(value.clone());
+        assert_eq!(event, WebhookEvent::Unknown(value));
+        assert_eq!(event.r#type(), Some("agent_updated"));
+        assert_eq!(round_trip(serde_json::json!({ "data": {} })).r#type(), None);
+    }
+
+    #[test]
+    fn known_type_with_malformed_data() {
+        let value = serde_json::json!({
+            "type": "voice_removal_notice",
+            "event_timestamp": SENT_AT,
+            "data": { "removal_date": "2026-01-01" }
+        });
+        assert!(serde_json::from_value::<WebhookEvent>(value).is_err());
+    }
+}